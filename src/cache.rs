@@ -0,0 +1,253 @@
+//! Persistent cache for slow, rarely-changing module results (GPU name, shell version, packages...).
+//! One JSON file per key under `~/.cache/novafetch/` (XDG); entries expire after a TTL or when
+//! the mtime of one of their hint paths (e.g. `/var/lib/dpkg/status`) changes.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// How the cache is used for this run (`--no-cache`, `--refresh-cache`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheMode {
+    /// Read valid entries, write fresh ones.
+    Use,
+    /// Ignore existing entries, recompute and write.
+    Refresh,
+    /// Neither read nor write.
+    Off,
+}
+
+/// A fill lock older than this is ignored. A `--fill-cache` run that gave up on a hung module
+/// leaves its lock behind, so the module is retried at most once per this interval.
+const FILL_LOCK_TTL: Duration = Duration::from_secs(600);
+
+/// What a module caches and when the entry becomes stale.
+pub struct CachePolicy {
+    pub ttl: Duration,
+    /// Files/dirs whose mtime invalidates the entry (missing paths are recorded as such).
+    pub hints: Vec<PathBuf>,
+}
+
+impl CachePolicy {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            hints: Vec::new(),
+        }
+    }
+
+    pub fn with_hint(mut self, path: impl Into<PathBuf>) -> Self {
+        self.hints.push(path.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Hint {
+    path: PathBuf,
+    mtime_ms: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entry<T> {
+    created_ms: u64,
+    hints: Vec<Hint>,
+    value: T,
+}
+
+#[derive(Debug, Clone)]
+pub struct Cache {
+    dir: Option<PathBuf>,
+    mode: CacheMode,
+}
+
+impl Cache {
+    pub fn new(mode: CacheMode) -> Self {
+        Self {
+            dir: Self::default_dir(),
+            mode,
+        }
+    }
+
+    /// Default cache dir: `~/.cache/novafetch` (XDG).
+    pub fn default_dir() -> Option<PathBuf> {
+        dirs::cache_dir().map(|d| d.join("novafetch"))
+    }
+
+    /// True if entries are written (the cache is on and has a directory).
+    pub fn writable(&self) -> bool {
+        self.dir.is_some() && self.mode != CacheMode::Off
+    }
+
+    /// Returns the cached value for `key` if still valid, else runs `compute` and stores its result.
+    /// I/O and decode errors are treated as a miss; the cache never makes a fetch fail.
+    pub fn get_or_insert<T, F>(&self, key: &str, policy: &CachePolicy, compute: F) -> T
+    where
+        T: Serialize + DeserializeOwned,
+        F: FnOnce() -> T,
+    {
+        let Some((path, hints)) = self.slot(key, policy) else {
+            return compute();
+        };
+        if let Some(value) = self.lookup(&path, policy, &hints) {
+            return value;
+        }
+        store(&path, hints, compute())
+    }
+
+    /// Like `get_or_insert`, but a `None` from `compute` (failed command, timeout) is not stored.
+    pub fn get_or_try_insert<T, F>(&self, key: &str, policy: &CachePolicy, compute: F) -> Option<T>
+    where
+        T: Serialize + DeserializeOwned,
+        F: FnOnce() -> Option<T>,
+    {
+        let Some((path, hints)) = self.slot(key, policy) else {
+            return compute();
+        };
+        if let Some(value) = self.lookup(&path, policy, &hints) {
+            return Some(value);
+        }
+        Some(store(&path, hints, compute()?))
+    }
+
+    /// True while a `--fill-cache` run holds the fill lock of `key` (see `lock_fill`).
+    pub fn fill_locked(&self, key: &str) -> bool {
+        self.lock_path(key)
+            .and_then(|path| lock_age(&path))
+            .is_some_and(|age| age < FILL_LOCK_TTL)
+    }
+
+    /// Takes the fill lock of `key` (`<key>.fill.lock`), so only one `--fill-cache` run computes
+    /// it at a time. None when another run holds it or the cache is off.
+    pub fn lock_fill(&self, key: &str) -> Option<FillLock> {
+        let path = self.lock_path(key)?;
+        if lock_age(&path).is_some_and(|age| age >= FILL_LOCK_TTL) {
+            let _ = std::fs::remove_file(&path);
+        }
+        if let Some(parent) = path.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+        std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .ok()?;
+        Some(FillLock { path })
+    }
+
+    fn lock_path(&self, key: &str) -> Option<PathBuf> {
+        match (&self.dir, self.mode) {
+            (Some(dir), CacheMode::Use | CacheMode::Refresh) => {
+                Some(dir.join(format!("{}.fill.lock", key)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Entry path and current hint mtimes for `key`; None when the cache is off.
+    fn slot(&self, key: &str, policy: &CachePolicy) -> Option<(PathBuf, Vec<Hint>)> {
+        match (&self.dir, self.mode) {
+            (Some(dir), CacheMode::Use | CacheMode::Refresh) => Some((
+                dir.join(format!("{}.json", key)),
+                current_hints(&policy.hints),
+            )),
+            _ => None,
+        }
+    }
+
+    fn lookup<T: DeserializeOwned>(
+        &self,
+        path: &Path,
+        policy: &CachePolicy,
+        hints: &[Hint],
+    ) -> Option<T> {
+        if self.mode != CacheMode::Use {
+            return None;
+        }
+        read_valid(path, policy.ttl, hints)
+    }
+}
+
+/// Fill lock of one key; released when dropped.
+pub struct FillLock {
+    path: PathBuf,
+}
+
+impl FillLock {
+    /// Leaves the lock file in place until it expires (`FILL_LOCK_TTL`).
+    pub fn keep(self) {
+        std::mem::forget(self);
+    }
+}
+
+impl Drop for FillLock {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Time since the lock file was created; None if there is none.
+fn lock_age(path: &Path) -> Option<Duration> {
+    let modified = std::fs::metadata(path).and_then(|m| m.modified()).ok()?;
+    Some(
+        SystemTime::now()
+            .duration_since(modified)
+            .unwrap_or_default(),
+    )
+}
+
+fn store<T: Serialize>(path: &Path, hints: Vec<Hint>, value: T) -> T {
+    let entry = Entry {
+        created_ms: now_ms(),
+        hints,
+        value,
+    };
+    let _ = write_atomic(path, &entry);
+    entry.value
+}
+
+fn read_valid<T: DeserializeOwned>(path: &Path, ttl: Duration, hints: &[Hint]) -> Option<T> {
+    let s = std::fs::read_to_string(path).ok()?;
+    let entry: Entry<T> = serde_json::from_str(&s).ok()?;
+    let age_ms = now_ms().checked_sub(entry.created_ms)?;
+    if u128::from(age_ms) >= ttl.as_millis() || entry.hints != hints {
+        return None;
+    }
+    Some(entry.value)
+}
+
+/// Writes to a temp file and renames, so concurrent runs never read a partial entry.
+fn write_atomic<T: Serialize>(path: &Path, entry: &Entry<T>) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let s = serde_json::to_string(entry)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    let tmp = path.with_extension(format!("json.{}.tmp", std::process::id()));
+    std::fs::write(&tmp, s)?;
+    std::fs::rename(&tmp, path)
+}
+
+fn current_hints(paths: &[PathBuf]) -> Vec<Hint> {
+    paths
+        .iter()
+        .map(|p| Hint {
+            path: p.clone(),
+            mtime_ms: std::fs::metadata(p)
+                .and_then(|m| m.modified())
+                .ok()
+                .and_then(to_ms),
+        })
+        .collect()
+}
+
+fn now_ms() -> u64 {
+    to_ms(SystemTime::now()).unwrap_or(0)
+}
+
+fn to_ms(t: SystemTime) -> Option<u64> {
+    t.duration_since(UNIX_EPOCH)
+        .ok()
+        .and_then(|d| u64::try_from(d.as_millis()).ok())
+}
@@ -0,0 +1,356 @@
+//! Config validation: TOML parse errors and semantic problems (unknown module keys, invalid
+//! choices) with the file position and a suggestion, for each layer loaded by `layers`.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use super::AppConfig;
+use crate::info;
+
+/// A problem in a config file. Line and column are 1-based; None when the position is unknown.
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub path: PathBuf,
+    pub position: Option<(usize, usize)>,
+    pub message: String,
+    /// Suggested fix, e.g. "did you mean \"memory\"?".
+    pub help: Option<String>,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.path.display())?;
+        if let Some((line, column)) = self.position {
+            write!(f, ":{}:{}", line, column)?;
+        }
+        write!(f, ": {}", self.message)?;
+        if let Some(help) = &self.help {
+            write!(f, " ({})", help)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses config text. A TOML or type error is returned as Err (the file cannot be used).
+pub fn parse(src: &str, path: &Path) -> Result<AppConfig, Diagnostic> {
+    toml::from_str(src).map_err(|e| Diagnostic {
+        path: path.to_path_buf(),
+        position: e.span().map(|span| line_column(src, span.start)),
+        message: e.message().trim().replace('\n', "; "),
+        help: parse_help(e.message()),
+    })
+}
+
+/// Help for common type errors.
+pub(super) fn parse_help(message: &str) -> Option<String> {
+    if message.contains("expected an array of length 3") || message.contains("expected u8") {
+        Some("colors are [R, G, B] with values from 0 to 255, e.g. [59, 130, 246]".into())
+    } else if message.contains("expected a boolean") {
+        Some("use true or false without quotes".into())
+    } else if message.contains("expected a sequence") {
+        Some("use a list, e.g. [\"os\", \"cpu\"]".into())
+    } else {
+        None
+    }
+}
+
+/// Settings with a fixed set of values: (path, current value, accepted values). The first
+/// values are the documented ones; later ones are accepted aliases.
+fn choices(config: &AppConfig) -> Vec<(&'static str, &str, &'static [&'static str])> {
+    vec![
+        (
+            "theme.mode",
+            &config.theme.mode,
+            &["gradient", "solid", "distro"],
+        ),
+        (
+            "general.unit_type",
+            &config.general.unit_type,
+            &["standard", "iec", "si", ""],
+        ),
+        (
+            "general.icon_set",
+            &config.general.icon_set,
+            &["nerd", "unicode", "emoji", "none", "off"],
+        ),
+        (
+            "general.align_scope",
+            &config.general.align_scope,
+            &["section", "global"],
+        ),
+        (
+            "general.image_renderer",
+            &config.general.image_renderer,
+            &["graphics", "blocks", "ascii", "braille", "halfblocks"],
+        ),
+        (
+            "general.image_colors",
+            &config.general.image_colors,
+            &["truecolor", "256", "16", "none", "off"],
+        ),
+        ("display.mode", &config.display.mode, &["tree", "flat"]),
+        (
+            "display.header_style",
+            &config.display.header_style,
+            &["centered", "left", "plain", "none"],
+        ),
+        (
+            "ascii.position",
+            &config.ascii.position,
+            &["left", "right", "top", "bottom"],
+        ),
+        (
+            "ascii.size",
+            &config.ascii.size,
+            &["auto", "small", "large"],
+        ),
+        (
+            "ascii.valign",
+            &config.ascii.valign,
+            &["top", "center", "bottom", "middle"],
+        ),
+    ]
+}
+
+/// Semantic checks of a parsed config: choice values and module keys. `scope` is the dotted
+/// key the settings are under in `src` ("" for a whole file, e.g. "profiles.work"); `src` may
+/// be empty (no positions). `custom` are the `[[custom]]` keys of the whole layered config.
+pub fn validate(
+    config: &AppConfig,
+    src: &str,
+    path: &Path,
+    scope: &str,
+    custom: &[&str],
+) -> Vec<Diagnostic> {
+    let locate = |key: &str, needle: Option<&str>| match scope {
+        "" => locate(src, key, needle),
+        _ => locate(src, &format!("{}.{}", scope, key), needle),
+    };
+    let mut problems = Vec::new();
+    let mut report = |position: Option<(usize, usize)>, message: String, help: String| {
+        problems.push(Diagnostic {
+            path: path.to_path_buf(),
+            position,
+            message,
+            help: Some(help),
+        });
+    };
+
+    for (key, value, allowed) in choices(config) {
+        if allowed.contains(&value.trim().to_lowercase().as_str()) {
+            continue;
+        }
+        let documented: Vec<&str> = allowed.iter().copied().filter(|v| !v.is_empty()).collect();
+        let help = match did_you_mean(value, &documented) {
+            Some(s) => format!("did you mean \"{}\"?", s),
+            None => format!("expected one of: {}", documented.join(", ")),
+        };
+        report(
+            locate(key, None),
+            format!("invalid {} \"{}\"", key, value),
+            help,
+        );
+    }
+
+    // Module keys: built-ins plus [[custom]] entries
+    let mut known: Vec<&str> = info::registry().iter().map(|m| m.key()).collect();
+    known.extend(config.custom.iter().map(|c| c.key.as_str()));
+    known.extend(custom);
+    // In a list the key is a string value; in a table it is the name of an entry
+    let mut check_key = |setting: &str, key: &str, in_table: bool| {
+        let key = key.trim();
+        if key.is_empty() || known.contains(&key) {
+            return;
+        }
+        let position = if in_table {
+            locate(&format!("{}.{}", setting, key), None)
+        } else {
+            locate(setting, Some(key))
+        };
+        report(
+            position.or_else(|| locate(setting, None)),
+            format!("unknown module key \"{}\" in {}", key, setting),
+            match did_you_mean(key, &known) {
+                Some(s) => format!("did you mean \"{}\"?", s),
+                None => "see the module list in the README or add a [[custom]] module".into(),
+            },
+        );
+    };
+    for key in &config.layout {
+        check_key("layout", key, false);
+    }
+    for section in &config.display.sections {
+        for key in &section.modules {
+            check_key("display.sections.modules", key, false);
+        }
+    }
+    for key in config.modules.keys() {
+        check_key("modules", key, true);
+    }
+    for key in config.general.module_timeouts.keys() {
+        check_key("general.module_timeouts", key, true);
+    }
+    // The pre-layout format still parses (unknown keys are ignored) but does nothing
+    if let Some(key) = legacy_key(src).filter(|_| scope.is_empty()) {
+        report(
+            locate(&key, None),
+            "legacy config format (show_* / [colors]) is ignored".into(),
+            "convert it with `novafetch config migrate <file>`".into(),
+        );
+    }
+    problems.sort_by_key(|p| p.position.unwrap_or((usize::MAX, 0)));
+    problems
+}
+
+/// First top-level key of the legacy format: a `show_*` toggle or the `[colors]` table.
+fn legacy_key(src: &str) -> Option<String> {
+    let table: toml::Table = src.parse().ok()?;
+    table
+        .iter()
+        .find(|(k, v)| (k.starts_with("show_") && v.is_bool()) || (*k == "colors" && v.is_table()))
+        .map(|(k, _)| k.clone())
+}
+
+/// Closest candidate to `input` within a small edit distance.
+pub(super) fn did_you_mean<'a>(input: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let input = input.trim().to_lowercase();
+    let max = (input.chars().count() / 3).max(2);
+    candidates
+        .iter()
+        .map(|&c| (edit_distance(&input, c), c))
+        .filter(|&(d, _)| d <= max)
+        .min_by_key(|&(d, _)| d)
+        .map(|(_, c)| c)
+}
+
+/// Levenshtein distance.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let cur = row[j + 1];
+            row[j + 1] = if ca == cb {
+                prev
+            } else {
+                1 + prev.min(cur).min(row[j])
+            };
+            prev = cur;
+        }
+    }
+    row[b.len()]
+}
+
+/// Position of the setting `key` (dotted path, e.g. "theme.mode" or "modules.cpu") in `src`:
+/// its value, its `[table]` header, or with `needle` the first `"needle"` string after it.
+/// The source is scanned line by line; keys in inline tables are not found.
+pub(super) fn locate(src: &str, key: &str, needle: Option<&str>) -> Option<(usize, usize)> {
+    let mut table = String::new();
+    let mut offset = 0;
+    for line in src.split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+        let found = if trimmed.starts_with('[') {
+            table = trimmed
+                .trim_end()
+                .trim_start_matches('[')
+                .trim_end_matches(']')
+                .trim()
+                .to_string();
+            (table == key).then_some(start + indent)
+        } else if let Some((name, value)) = trimmed.split_once('=') {
+            let name = name.trim().trim_matches('"');
+            let full = if table.is_empty() {
+                name.to_string()
+            } else {
+                format!("{}.{}", table, name)
+            };
+            let value_start = start + line.len() - value.trim_start().len();
+            (full == key).then_some(value_start)
+        } else {
+            None
+        };
+        if let Some(pos) = found {
+            let pos = match needle {
+                Some(n) => pos + src[pos..].find(&format!("\"{}\"", n))?,
+                None => pos,
+            };
+            return Some(line_column(src, pos));
+        }
+    }
+    None
+}
+
+/// 1-based line and column (in characters) of byte `offset` in `src`.
+fn line_column(src: &str, offset: usize) -> (usize, usize) {
+    let before = &src[..offset.min(src.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before[before.rfind('\n').map_or(0, |i| i + 1)..]
+        .chars()
+        .count()
+        + 1;
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::DEFAULT_CONFIG;
+
+    fn as_toml(config: &AppConfig) -> String {
+        toml::to_string(config).unwrap()
+    }
+
+    #[test]
+    fn default_config_matches_defaults() {
+        let parsed = parse(DEFAULT_CONFIG, Path::new("default.toml")).unwrap();
+        assert_eq!(as_toml(&parsed), as_toml(&AppConfig::default()));
+        assert!(validate(&parsed, DEFAULT_CONFIG, Path::new("default.toml"), "", &[]).is_empty());
+    }
+
+    #[test]
+    fn unknown_layout_key_has_position() {
+        let src = "layout = [\"os\", \"memroy\"]\n\n[theme]\nmode = \"solid\"\n";
+        let config = parse(src, Path::new("c.toml")).unwrap();
+        let problems = validate(&config, src, Path::new("c.toml"), "", &[]);
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].position, Some((1, 17)));
+        assert_eq!(
+            problems[0].help.as_deref(),
+            Some("did you mean \"memory\"?")
+        );
+    }
+
+    #[test]
+    fn invalid_choice_points_at_value() {
+        let src = "[theme]\nmode = \"gradiant\"\n";
+        let config = parse(src, Path::new("c.toml")).unwrap();
+        let problems = validate(&config, src, Path::new("c.toml"), "", &[]);
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].position, Some((2, 8)));
+        assert_eq!(
+            problems[0].to_string(),
+            "c.toml:2:8: invalid theme.mode \"gradiant\" (did you mean \"gradient\"?)"
+        );
+    }
+
+    #[test]
+    fn type_error_has_position_and_help() {
+        let e = parse("[theme]\nprimary_color = [1, 2]\n", Path::new("c.toml")).unwrap_err();
+        assert_eq!(e.position.map(|p| p.0), Some(2));
+        assert!(e.help.unwrap().starts_with("colors are [R, G, B]"));
+    }
+
+    #[test]
+    fn locate_finds_tables_and_needles() {
+        let src = "layout = [\"os\"]\n\n[modules.cpu]\nformat = \"x\"\n";
+        assert_eq!(locate(src, "modules.cpu", None), Some((3, 1)));
+        assert_eq!(locate(src, "modules.cpu.format", None), Some((4, 10)));
+        assert_eq!(locate(src, "layout", Some("os")), Some((1, 11)));
+        assert_eq!(locate(src, "theme.mode", None), None);
+    }
+}
@@ -0,0 +1,620 @@
+//! Layered config. Later layers win; tables are merged key by key, other values (including
+//! lists such as `layout` or `[[custom]]`) are replaced as a whole:
+//!
+//! 1. system files: `novafetch/config.toml` in `$XDG_CONFIG_DIRS` (default `/etc/xdg`)
+//! 2. the user file (`--config` or `~/.config/novafetch/config.toml`)
+//! 3. the `[profiles.<name>]` table picked with `--profile` / `NOVAFETCH_PROFILE`
+//! 4. `NOVAFETCH_<TABLE>__<KEY>` environment variables
+//! 5. `--set table.key=value`
+//!
+//! A file's `include = [...]` fragments are merged just below that file.
+
+use std::path::{Path, PathBuf};
+use toml::{Table, Value};
+
+use super::check::{self, Diagnostic};
+use super::{expand_tilde, AppConfig};
+
+/// Prefix of environment overrides; `__` separates tables, e.g. `NOVAFETCH_THEME__MODE`.
+pub const ENV_PREFIX: &str = "NOVAFETCH_";
+
+/// Environment variable picking a profile when `--profile` is not given.
+pub const PROFILE_ENV: &str = "NOVAFETCH_PROFILE";
+
+/// Includes nested deeper than this are reported (and usually a cycle).
+const MAX_INCLUDE_DEPTH: usize = 8;
+
+/// Settings on top of the config files (command line and environment).
+#[derive(Debug, Clone, Default)]
+pub struct Overrides {
+    /// Profile name (`--profile`); `NOVAFETCH_PROFILE` if None.
+    pub profile: Option<String>,
+    /// `--set` arguments: `table.key=value`.
+    pub set: Vec<String>,
+}
+
+/// The merged config of a run.
+pub struct Loaded {
+    pub config: AppConfig,
+    /// Config files that were read, lowest priority first.
+    pub files: Vec<PathBuf>,
+    /// Problems in any layer; a layer that cannot be used is skipped.
+    pub problems: Vec<Diagnostic>,
+    /// Problems in the profiles this run does not use (reported by `config check`).
+    pub profile_problems: Vec<Diagnostic>,
+}
+
+/// One layer: its settings and where they came from. `src` is the file text (positions) and
+/// `scope` the dotted key of the settings in it ("" for a whole file).
+struct Layer {
+    origin: PathBuf,
+    src: String,
+    scope: String,
+    table: Table,
+    config: AppConfig,
+}
+
+/// A `[profiles.<name>]` table as defined in one file.
+struct Profile {
+    name: String,
+    origin: PathBuf,
+    src: String,
+    value: Value,
+}
+
+#[derive(Default)]
+struct Loader {
+    layers: Vec<Layer>,
+    /// Profile tables in file order, lowest priority first.
+    profiles: Vec<Profile>,
+    files: Vec<PathBuf>,
+    problems: Vec<Diagnostic>,
+}
+
+/// Loads all layers for the user file `user`. Missing system and user files are skipped.
+pub fn load(user: &Path, overrides: &Overrides) -> Loaded {
+    let mut loader = Loader::default();
+    for path in system_paths() {
+        if path.is_file() && path != user {
+            loader.file(&path, &mut Vec::new());
+        }
+    }
+    if user.is_file() {
+        loader.file(user, &mut Vec::new());
+    }
+
+    let mut merged = Table::new();
+    for layer in &loader.layers {
+        merge(&mut merged, layer.table.clone());
+    }
+    let file_layers = loader.layers.len();
+
+    let profile = overrides
+        .profile
+        .clone()
+        .or_else(|| std::env::var(PROFILE_ENV).ok())
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty());
+    if let Some(name) = &profile {
+        loader.profile(name);
+    }
+    loader.environment(&merged);
+    for arg in &overrides.set {
+        loader.set(arg, &merged);
+    }
+    for layer in &loader.layers[file_layers..] {
+        merge(&mut merged, layer.table.clone());
+    }
+
+    let config = match Value::Table(merged).try_into::<AppConfig>() {
+        Ok(config) => config,
+        Err(e) => {
+            loader.problems.push(problem(
+                Path::new("config"),
+                format!(
+                    "{} (using the default config for this run)",
+                    e.message().trim()
+                ),
+            ));
+            AppConfig::default()
+        }
+    };
+    let custom: Vec<&str> = config.custom.iter().map(|c| c.key.as_str()).collect();
+    for layer in &loader.layers {
+        loader.problems.extend(layer.validate(&custom));
+    }
+    let mut profile_problems = Vec::new();
+    for other in loader
+        .profiles
+        .iter()
+        .filter(|p| profile.as_ref() != Some(&p.name))
+    {
+        match other.layer() {
+            Ok(layer) => profile_problems.extend(layer.validate(&custom)),
+            Err(e) => profile_problems.push(e),
+        }
+    }
+    Loaded {
+        config,
+        files: loader.files,
+        problems: loader.problems,
+        profile_problems,
+    }
+}
+
+/// System config files, lowest priority first (`$XDG_CONFIG_DIRS` lists the most important
+/// directory first).
+fn system_paths() -> Vec<PathBuf> {
+    let dirs = std::env::var("XDG_CONFIG_DIRS")
+        .ok()
+        .filter(|d| !d.trim().is_empty())
+        .unwrap_or_else(|| "/etc/xdg".into());
+    dirs.split(':')
+        .filter(|d| Path::new(d).is_absolute())
+        .rev()
+        .map(|d| Path::new(d).join("novafetch").join("config.toml"))
+        .collect()
+}
+
+impl Loader {
+    /// Adds the file at `path` as a layer, after its includes. `stack` holds the including
+    /// files (cycle detection).
+    fn file(&mut self, path: &Path, stack: &mut Vec<PathBuf>) {
+        let src = match std::fs::read_to_string(path) {
+            Ok(s) => s,
+            Err(e) => {
+                let message = format!("cannot read {}: {}", path.display(), e);
+                self.problems.push(problem(path, message));
+                return;
+            }
+        };
+        let config = match check::parse(&src, path) {
+            Ok(config) => config,
+            Err(mut e) => {
+                e.message.push_str("; file ignored for this run");
+                self.problems.push(e);
+                return;
+            }
+        };
+        // Parsing succeeded above, so the text is valid TOML
+        let mut table: Table = src.parse().unwrap_or_default();
+
+        if let Some(include) = table.remove("include") {
+            stack.push(canonical(path));
+            self.includes(path, &src, include, stack);
+            stack.pop();
+        }
+        self.files.push(path.to_path_buf());
+        match table.remove("profiles") {
+            Some(Value::Table(profiles)) => {
+                for (name, value) in profiles {
+                    self.profiles.push(Profile {
+                        name,
+                        origin: path.to_path_buf(),
+                        src: src.clone(),
+                        value,
+                    });
+                }
+            }
+            Some(_) => self.report_at(path, &src, "profiles", "profiles must be a table"),
+            None => {}
+        }
+        self.layers.push(Layer {
+            origin: path.to_path_buf(),
+            src,
+            scope: String::new(),
+            table,
+            config,
+        });
+    }
+
+    /// Loads the `include` list of `path`; relative paths are relative to that file.
+    fn includes(&mut self, path: &Path, src: &str, include: Value, stack: &mut Vec<PathBuf>) {
+        let names: Vec<String> = match include {
+            Value::String(s) => vec![s],
+            Value::Array(items) if items.iter().all(Value::is_str) => items
+                .into_iter()
+                .filter_map(|v| v.as_str().map(str::to_string))
+                .collect(),
+            _ => {
+                return self.report_at(path, src, "include", "include must be a list of paths");
+            }
+        };
+        let dir = path.parent().unwrap_or(Path::new("."));
+        for name in names {
+            let included = dir.join(expand_tilde(&name));
+            let position = check::locate(src, "include", Some(&name));
+            let message = if stack.contains(&canonical(&included)) {
+                format!("include \"{}\" includes itself", name)
+            } else if stack.len() >= MAX_INCLUDE_DEPTH {
+                format!("include \"{}\" is nested too deeply", name)
+            } else if !included.is_file() {
+                format!("include \"{}\" not found", name)
+            } else {
+                self.file(&included, stack);
+                continue;
+            };
+            self.problems.push(Diagnostic {
+                position,
+                ..problem(path, message)
+            });
+        }
+    }
+
+    /// Adds the profile `name` as a layer per file that defines it.
+    fn profile(&mut self, name: &str) {
+        let mut found = false;
+        for profile in self.profiles.iter().filter(|p| p.name == name) {
+            found = true;
+            match profile.layer() {
+                Ok(layer) => self.layers.push(layer),
+                Err(mut e) => {
+                    e.message.push_str("; ignored");
+                    self.problems.push(e);
+                }
+            }
+        }
+        if found {
+            return;
+        }
+        let mut known: Vec<&str> = self.profiles.iter().map(|p| p.name.as_str()).collect();
+        known.sort_unstable();
+        known.dedup();
+        self.problems.push(Diagnostic {
+            help: Some(match check::did_you_mean(name, &known) {
+                Some(s) => format!("did you mean \"{}\"?", s),
+                None if known.is_empty() => "no [profiles.<name>] tables defined".into(),
+                None => format!("defined profiles: {}", known.join(", ")),
+            }),
+            ..problem(
+                &PathBuf::from(format!("profile \"{}\"", name)),
+                "unknown profile".into(),
+            )
+        });
+    }
+
+    /// Adds a layer per `NOVAFETCH_<TABLE>__<KEY>` variable, sorted by name. A top-level
+    /// setting that is not a table (`NOVAFETCH_LAYOUT`) needs no `__`.
+    fn environment(&mut self, merged: &Table) {
+        let defaults = default_table();
+        let mut vars: Vec<(String, String)> = std::env::vars_os()
+            .filter_map(|(k, v)| Some((k.into_string().ok()?, v.into_string().ok()?)))
+            .filter(|(k, _)| k.starts_with(ENV_PREFIX) && k != PROFILE_ENV)
+            .collect();
+        vars.sort();
+        for (name, raw) in vars {
+            let key = name[ENV_PREFIX.len()..].to_lowercase().replace("__", ".");
+            let top_level = defaults.get(&key).is_some_and(|v| !v.is_table());
+            if !key.contains('.') && !top_level {
+                // Other NOVAFETCH_* variables (e.g. from `--format shell`) are not settings;
+                // only names that look like a misspelled setting are reported
+                if let Some(help) = env_help(&key, &defaults) {
+                    self.problems.push(Diagnostic {
+                        help: Some(help),
+                        ..problem(Path::new(&name), "not a config setting; ignored".into())
+                    });
+                }
+                continue;
+            }
+            self.assign(PathBuf::from(name), &key, &raw, merged);
+        }
+    }
+
+    /// Adds a `--set table.key=value` layer.
+    fn set(&mut self, arg: &str, merged: &Table) {
+        let origin = PathBuf::from(format!("--set {}", arg));
+        match arg.split_once('=') {
+            Some((key, raw)) if !key.trim().is_empty() => {
+                self.assign(origin, key.trim(), raw, merged)
+            }
+            _ => self.problems.push(Diagnostic {
+                help: Some("use --set table.key=value, e.g. --set theme.mode=solid".into()),
+                ..problem(&origin, "expected KEY=VALUE".into())
+            }),
+        }
+    }
+
+    /// Adds a layer setting the dotted `key` to the text `raw`.
+    fn assign(&mut self, origin: PathBuf, key: &str, raw: &str, merged: &Table) {
+        let current = lookup(merged, key).or_else(|| lookup(&default_table(), key));
+        let mut value = parse_value(raw, current.as_ref());
+        for part in key.rsplit('.') {
+            let mut table = Table::new();
+            table.insert(part.trim().to_string(), value);
+            value = Value::Table(table);
+        }
+        if let Value::Table(table) = value {
+            self.override_layer(origin, table);
+        }
+    }
+
+    /// Adds a layer without a file; type errors skip it, unknown settings are reported.
+    fn override_layer(&mut self, origin: PathBuf, table: Table) {
+        let mut unknown = Vec::new();
+        let parsed = serde_ignored::deserialize(Value::Table(table.clone()), |path| {
+            unknown.push(path.to_string())
+        });
+        for key in unknown {
+            self.problems.push(Diagnostic {
+                help: unknown_key_help(&key),
+                ..problem(&origin, format!("unknown setting \"{}\"; ignored", key))
+            });
+        }
+        match parsed {
+            Ok(config) => self.layers.push(Layer {
+                origin,
+                src: String::new(),
+                scope: String::new(),
+                table,
+                config,
+            }),
+            Err(e) => {
+                let message = e.message().trim().replace('\n', "; ");
+                self.problems.push(Diagnostic {
+                    help: check::parse_help(e.message()),
+                    ..problem(&origin, format!("{}; ignored", message))
+                });
+            }
+        }
+    }
+
+    fn report_at(&mut self, path: &Path, src: &str, key: &str, message: &str) {
+        self.problems.push(Diagnostic {
+            position: check::locate(src, key, None),
+            ..problem(path, message.into())
+        });
+    }
+}
+
+impl Layer {
+    fn validate(&self, custom: &[&str]) -> Vec<Diagnostic> {
+        check::validate(&self.config, &self.src, &self.origin, &self.scope, custom)
+    }
+}
+
+impl Profile {
+    /// The profile as a layer. A value that is not a valid config table is reported at the
+    /// profile's table in its file.
+    fn layer(&self) -> Result<Layer, Diagnostic> {
+        let scope = format!("profiles.{}", self.name);
+        let table = self.value.as_table();
+        // The file may only have subtables such as [profiles.work.theme]
+        let position = check::locate(&self.src, &scope, None).or_else(|| {
+            table?
+                .keys()
+                .find_map(|k| check::locate(&self.src, &format!("{}.{}", scope, k), None))
+        });
+        let error = |message: String, help: Option<String>| Diagnostic {
+            path: self.origin.clone(),
+            position,
+            message,
+            help,
+        };
+        let Some(table) = table else {
+            return Err(error(format!("{} must be a table", scope), None));
+        };
+        match Value::Table(table.clone()).try_into::<AppConfig>() {
+            Ok(config) => Ok(Layer {
+                origin: self.origin.clone(),
+                src: self.src.clone(),
+                scope,
+                table: table.clone(),
+                config,
+            }),
+            Err(e) => {
+                let message = e.message().trim().replace('\n', "; ");
+                Err(error(
+                    format!("{}: {}", scope, message),
+                    check::parse_help(e.message()),
+                ))
+            }
+        }
+    }
+}
+
+fn problem(origin: &Path, message: String) -> Diagnostic {
+    Diagnostic {
+        path: origin.to_path_buf(),
+        position: None,
+        message,
+        help: None,
+    }
+}
+
+/// Merges `top` into `base`: tables key by key, everything else replaced.
+fn merge(base: &mut Table, top: Table) {
+    for (key, value) in top {
+        match (base.get_mut(&key), value) {
+            (Some(Value::Table(base)), Value::Table(top)) => merge(base, top),
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+/// The default config as a table (settings that are unset by default are missing).
+fn default_table() -> Table {
+    Value::try_from(AppConfig::default())
+        .ok()
+        .and_then(|v| v.as_table().cloned())
+        .unwrap_or_default()
+}
+
+/// Suggestion for the unknown dotted setting `key`: the closest setting in the same table.
+fn unknown_key_help(key: &str) -> Option<String> {
+    let (parent, name) = key.rsplit_once('.').unwrap_or(("", key));
+    let defaults = default_table();
+    let table = match parent {
+        "" => defaults.clone(),
+        _ => lookup(&defaults, parent)?.as_table()?.clone(),
+    };
+    let known: Vec<&str> = table.keys().map(String::as_str).collect();
+    let found = check::did_you_mean(name, &known)?;
+    Some(match parent {
+        "" => format!("did you mean \"{}\"?", found),
+        _ => format!("did you mean \"{}.{}\"?", parent, found),
+    })
+}
+
+/// Help for a `NOVAFETCH_<NAME>` variable that is not a setting but looks like a misspelled one:
+/// close to a top-level setting, or `<TABLE>_<KEY>` with a single underscore.
+fn env_help(key: &str, defaults: &Table) -> Option<String> {
+    let env_name = |key: &str| format!("{}{}", ENV_PREFIX, key.replace('.', "__").to_uppercase());
+    let top_level: Vec<&str> = defaults
+        .iter()
+        .filter(|(_, v)| !v.is_table())
+        .map(|(k, _)| k.as_str())
+        .collect();
+    if let Some(found) = check::did_you_mean(key, &top_level) {
+        return Some(format!("did you mean {}?", env_name(found)));
+    }
+    defaults.iter().find_map(|(table, value)| {
+        let rest = key.strip_prefix(table.as_str())?.strip_prefix('_')?;
+        value.as_table()?.contains_key(rest).then(|| {
+            format!(
+                "tables are separated by \"__\": {}",
+                env_name(&format!("{}.{}", table, rest))
+            )
+        })
+    })
+}
+
+/// Value at the dotted `key`.
+fn lookup(table: &Table, key: &str) -> Option<Value> {
+    let mut parts = key.split('.');
+    let mut value = table.get(parts.next()?.trim())?;
+    for part in parts {
+        value = value.get(part.trim())?;
+    }
+    Some(value.clone())
+}
+
+/// An override's value: TOML (`true`, `3`, `[1, 2, 3]`, `"text"`), else the text itself. For a
+/// setting that is currently a string the text is kept as is (`separator=10`).
+fn parse_value(raw: &str, current: Option<&Value>) -> Value {
+    let parsed = format!("v = {}", raw)
+        .parse::<Table>()
+        .ok()
+        .and_then(|mut t| t.remove("v"));
+    match parsed {
+        Some(value) if value.is_str() || !current.is_some_and(Value::is_str) => value,
+        _ => Value::String(raw.to_string()),
+    }
+}
+
+/// `path` with symlinks and `..` resolved, for include cycle detection.
+fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_value_coerces_by_current_type() {
+        let string = Value::String("x".into());
+        assert_eq!(parse_value("true", None), Value::Boolean(true));
+        assert_eq!(parse_value("300", None), Value::Integer(300));
+        assert_eq!(parse_value("10", Some(&string)), Value::String("10".into()));
+        assert_eq!(parse_value("solid", None), Value::String("solid".into()));
+        assert_eq!(
+            parse_value("\"a b\"", Some(&string)),
+            Value::String("a b".into())
+        );
+        assert_eq!(
+            parse_value("[1, 2, 3]", None),
+            Value::Array(vec![1.into(), 2.into(), 3.into()])
+        );
+    }
+
+    #[test]
+    fn merge_joins_tables_and_replaces_lists() {
+        let mut base: Table =
+            "layout = [\"os\", \"cpu\"]\n[theme]\nmode = \"solid\"\ncolors_from_logo = true"
+                .parse()
+                .unwrap();
+        let top: Table = "layout = [\"kernel\"]\n[theme]\nmode = \"distro\""
+            .parse()
+            .unwrap();
+        merge(&mut base, top);
+        assert_eq!(
+            lookup(&base, "layout"),
+            Some(Value::Array(vec!["kernel".into()]))
+        );
+        assert_eq!(
+            lookup(&base, "theme.mode"),
+            Some(Value::String("distro".into()))
+        );
+        assert_eq!(
+            lookup(&base, "theme.colors_from_logo"),
+            Some(Value::Boolean(true))
+        );
+    }
+
+    #[test]
+    fn profile_problems_have_positions() {
+        let src = "layout = [\"os\"]\n\n[profiles.work.theme]\nmode = \"gradiant\"\n\n[profiles.home]\nlayout = \"os\"\n";
+        let mut table: Table = src.parse().unwrap();
+        let Some(Value::Table(profiles)) = table.remove("profiles") else {
+            panic!("no profiles");
+        };
+        let layers: Vec<Result<Layer, Diagnostic>> = profiles
+            .into_iter()
+            .map(|(name, value)| {
+                let origin = PathBuf::from("c.toml");
+                let src = src.to_string();
+                Profile {
+                    name,
+                    origin,
+                    src,
+                    value,
+                }
+                .layer()
+            })
+            .collect();
+        let home = layers[0].as_ref().err().unwrap();
+        assert_eq!(home.position, Some((6, 1)));
+        assert!(home.message.starts_with("profiles.home: "));
+        let work = layers[1].as_ref().ok().unwrap().validate(&[]);
+        assert_eq!(work.len(), 1);
+        assert_eq!(work[0].position, Some((4, 8)));
+        assert_eq!(work[0].message, "invalid theme.mode \"gradiant\"");
+    }
+
+    #[test]
+    fn assign_builds_nested_layer() {
+        let mut loader = Loader::default();
+        loader.set("theme.mode=solid", &Table::new());
+        loader.set("general.separator=10", &Table::new());
+        loader.set("nonsense", &Table::new());
+        assert_eq!(loader.layers.len(), 2);
+        assert_eq!(loader.layers[0].config.theme.mode, "solid");
+        assert_eq!(loader.layers[1].config.general.separator, "10");
+        assert_eq!(loader.problems.len(), 1);
+    }
+
+    #[test]
+    fn unknown_override_keys_are_reported() {
+        let mut loader = Loader::default();
+        loader.set("theme.mdoe=solid", &Table::new());
+        assert_eq!(loader.problems.len(), 1);
+        assert_eq!(
+            loader.problems[0].message,
+            "unknown setting \"theme.mdoe\"; ignored"
+        );
+        assert_eq!(
+            loader.problems[0].help.as_deref(),
+            Some("did you mean \"theme.mode\"?")
+        );
+
+        let defaults = default_table();
+        assert_eq!(
+            env_help("layotu", &defaults).as_deref(),
+            Some("did you mean NOVAFETCH_LAYOUT?")
+        );
+        assert!(env_help("theme_mode", &defaults).is_some());
+        assert_eq!(env_help("cpu_model", &defaults), None);
+    }
+}
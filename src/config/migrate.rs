@@ -0,0 +1,573 @@
+//! Config migration: the legacy NovaFetch format (`show_*` toggles, `[colors]`), neofetch's
+//! `config.conf` and fastfetch's JSONC config are turned into an equivalent AppConfig.
+//! Settings without a NovaFetch equivalent are listed as notes.
+
+use clap::ValueEnum;
+use std::path::Path;
+
+use super::AppConfig;
+use crate::info;
+use crate::ui::color;
+
+/// Kind of config being migrated (`config migrate --from`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Source {
+    /// Old NovaFetch format with `show_*` keys and a `[colors]` table
+    Legacy,
+    /// neofetch `config.conf`
+    Neofetch,
+    /// fastfetch `config.jsonc`
+    Fastfetch,
+}
+
+impl Source {
+    /// Guesses the kind from the file name and content.
+    pub fn detect(path: &Path, text: &str) -> Self {
+        let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+        if matches!(ext, "json" | "jsonc") || text.trim_start().starts_with('{') {
+            Source::Fastfetch
+        } else if ext == "conf" || text.contains("print_info()") {
+            Source::Neofetch
+        } else {
+            Source::Legacy
+        }
+    }
+}
+
+/// A migrated config and what could not be carried over.
+pub struct Migration {
+    pub config: AppConfig,
+    pub notes: Vec<String>,
+}
+
+/// Converts `text` (a config of kind `source`) into a NovaFetch config.
+pub fn migrate(source: Source, text: &str) -> Result<Migration, String> {
+    let mut m = Migration {
+        config: AppConfig::default(),
+        notes: Vec::new(),
+    };
+    match source {
+        Source::Legacy => legacy(&mut m, text)?,
+        Source::Neofetch => neofetch(&mut m, text),
+        Source::Fastfetch => fastfetch(&mut m, text)?,
+    }
+    Ok(m)
+}
+
+/// Sets the label and value colors. Labels are drawn solid (distro mode does that too), so
+/// the label color shows as is.
+fn set_label_colors(config: &mut AppConfig, label: Option<[u8; 3]>, value: Option<[u8; 3]>) {
+    if let Some(rgb) = label {
+        config.theme.primary_color = rgb;
+        config.theme.secondary_color = rgb;
+        if config.theme.mode == "gradient" {
+            config.theme.mode = "solid".into();
+        }
+    }
+    if let Some(rgb) = value {
+        config.theme.text_color = rgb;
+    }
+}
+
+/// Draws the logo in `colors`: distro mode paints built-in logos with `[ascii] colors`.
+fn set_logo_colors(config: &mut AppConfig, colors: Vec<[u8; 3]>) {
+    if !colors.is_empty() {
+        config.ascii.colors = colors;
+        config.theme.mode = "distro".into();
+    }
+}
+
+/// Appends `key` to the layout unless it is already there.
+fn push_key(layout: &mut Vec<String>, key: &str) {
+    if !layout.iter().any(|k| k == key) {
+        layout.push(key.to_string());
+    }
+}
+
+// --- Legacy NovaFetch ---
+
+/// Keys other than `show_*` and `[colors]` are kept as they are, so a file that mixes both
+/// formats keeps its newer settings. A file without legacy settings is an error.
+fn legacy(m: &mut Migration, text: &str) -> Result<(), String> {
+    let mut table: toml::Table = text
+        .parse()
+        .map_err(|e: toml::de::Error| e.message().to_string())?;
+    let toggles: Vec<(String, bool)> = table
+        .iter()
+        .filter_map(|(k, v)| Some((k.strip_prefix("show_")?.to_string(), v.as_bool()?)))
+        .collect();
+    table.retain(|k, _| !k.starts_with("show_"));
+    let colors = table.remove("colors");
+    if toggles.is_empty() && colors.is_none() {
+        return Err("no legacy settings found (no show_* keys and no [colors] table)".into());
+    }
+    m.config = toml::Value::Table(table)
+        .try_into()
+        .map_err(|e: toml::de::Error| e.message().to_string())?;
+
+    // Toggles become the layout, in the default module order
+    if !toggles.is_empty() {
+        m.config.layout = info::registry()
+            .iter()
+            .map(|module| module.key())
+            .filter(|&key| toggles.iter().any(|(k, on)| k == key && *on))
+            .map(str::to_string)
+            .collect();
+        for (key, _) in toggles {
+            if info::find_module(&key).is_none() {
+                m.notes
+                    .push(format!("show_{}: no module named \"{}\"", key, key));
+            }
+        }
+    }
+
+    if let Some(colors) = colors.as_ref().and_then(|c| c.as_table()) {
+        let mut named = |key: &str| {
+            let name = colors.get(key)?.as_str()?;
+            let rgb = color::parse_color(name);
+            if rgb.is_none() {
+                m.notes
+                    .push(format!("colors.{}: unknown color \"{}\"", key, name));
+            }
+            rgb
+        };
+        let (ascii, key, value) = (named("ascii"), named("key"), named("value"));
+        set_label_colors(&mut m.config, key, value);
+        set_logo_colors(&mut m.config, ascii.into_iter().collect());
+    }
+    Ok(())
+}
+
+// --- neofetch ---
+
+/// neofetch `info` functions and the module showing the same thing.
+const NEOFETCH_MODULES: &[(&str, &str)] = &[
+    ("title", "user_host"),
+    ("distro", "os"),
+    ("kernel", "kernel"),
+    ("uptime", "uptime"),
+    ("packages", "packages"),
+    ("shell", "shell"),
+    ("resolution", "resolution"),
+    ("de", "de"),
+    ("wm", "de"),
+    ("wm_theme", "theme"),
+    ("theme", "theme"),
+    ("icons", "theme"),
+    ("font", "theme"),
+    ("term", "terminal"),
+    ("term_font", "terminal_font"),
+    ("cpu", "cpu"),
+    ("cpu_usage", "cpu"),
+    ("gpu", "gpu"),
+    ("memory", "memory"),
+    ("disk", "disk"),
+    ("song", "media"),
+    ("local_ip", "local_ip"),
+    ("cols", "palette"),
+];
+
+/// neofetch functions that only draw decoration.
+const NEOFETCH_DECORATION: &[&str] = &["underline", "line_break"];
+
+fn neofetch(m: &mut Migration, text: &str) {
+    let mut layout = Vec::new();
+    let mut in_print_info = false;
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('#') || trimmed.is_empty() {
+            continue;
+        }
+        if trimmed.starts_with("print_info()") {
+            in_print_info = true;
+            continue;
+        }
+        if in_print_info {
+            if trimmed == "}" {
+                in_print_info = false;
+            } else if let Some(args) = trimmed.strip_prefix("info ") {
+                let function = shell_words(args).pop().unwrap_or_default();
+                match NEOFETCH_MODULES.iter().find(|(f, _)| *f == function) {
+                    Some((_, key)) => push_key(&mut layout, key),
+                    None if NEOFETCH_DECORATION.contains(&function.as_str()) => {}
+                    None => m
+                        .notes
+                        .push(format!("info {}: no equivalent module", function)),
+                }
+            } else if trimmed.starts_with("prin ") {
+                m.notes
+                    .push(format!("{}: custom lines are not migrated", trimmed));
+            }
+            continue;
+        }
+        if let Some((name, value)) = trimmed.split_once('=') {
+            neofetch_setting(m, name.trim(), value.trim());
+        }
+    }
+    if !layout.is_empty() {
+        m.config.layout = layout;
+    }
+}
+
+fn neofetch_setting(m: &mut Migration, name: &str, value: &str) {
+    // Arrays like `(4 6 1)` are read as plain words
+    let words = shell_words(&value.replace(['(', ')'], " "));
+    let first = words.first().map(String::as_str).unwrap_or("");
+    // neofetch colors are 0-255 color numbers; "distro" and "fg" keep the defaults
+    let color_at = |i: usize| {
+        words
+            .get(i)
+            .and_then(|w| w.parse::<u8>().ok())
+            .map(color::xterm_256)
+    };
+    match name {
+        "ascii_distro" if !first.is_empty() && first != "auto" => {
+            m.config.ascii.distro_override = Some(first.to_lowercase());
+        }
+        "ascii_colors" if first != "distro" => {
+            let colors = (0..words.len()).filter_map(color_at).collect();
+            set_logo_colors(&mut m.config, colors);
+        }
+        // title, @, underline, subtitle, colon, info
+        "colors" if first != "distro" => set_label_colors(&mut m.config, color_at(3), color_at(5)),
+        "separator" if !first.is_empty() => m.config.general.separator = format!("{} ", first),
+        "image_source" if !matches!(first, "" | "auto" | "ascii" | "wallpaper") => {
+            let path = first.replace("$HOME", "~");
+            if path.ends_with(".txt") {
+                m.config.ascii.logo_path = Some(path);
+            } else {
+                m.config.general.image_path = Some(path);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Splits shell words, removing quotes (no expansion).
+fn shell_words(s: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut quote = None;
+    let mut in_word = false;
+    for c in s.chars() {
+        match (quote, c) {
+            (None, '#') if !in_word => break,
+            (None, '"' | '\'') => {
+                quote = Some(c);
+                in_word = true;
+            }
+            (Some(q), c) if c == q => quote = None,
+            (None, c) if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut word));
+                    in_word = false;
+                }
+            }
+            (_, c) => {
+                word.push(c);
+                in_word = true;
+            }
+        }
+    }
+    if in_word {
+        words.push(word);
+    }
+    words
+}
+
+// --- fastfetch ---
+
+/// fastfetch module types (lowercase) and the module showing the same thing.
+const FASTFETCH_MODULES: &[(&str, &str)] = &[
+    ("title", "user_host"),
+    ("os", "os"),
+    ("kernel", "kernel"),
+    ("uptime", "uptime"),
+    ("packages", "packages"),
+    ("shell", "shell"),
+    ("display", "resolution"),
+    ("de", "de"),
+    ("wm", "de"),
+    ("wmtheme", "theme"),
+    ("theme", "theme"),
+    ("icons", "theme"),
+    ("font", "theme"),
+    ("terminal", "terminal"),
+    ("terminalfont", "terminal_font"),
+    ("cpu", "cpu"),
+    ("cpuusage", "cpu"),
+    ("gpu", "gpu"),
+    ("memory", "memory"),
+    ("swap", "swap"),
+    ("disk", "disk"),
+    ("localip", "local_ip"),
+    ("media", "media"),
+    ("player", "media"),
+    ("colors", "palette"),
+];
+
+/// fastfetch modules that only draw decoration.
+const FASTFETCH_DECORATION: &[&str] = &["separator", "break", "custom"];
+
+fn fastfetch(m: &mut Migration, text: &str) -> Result<(), String> {
+    let json: serde_json::Value =
+        serde_json::from_str(&strip_jsonc(text)).map_err(|e| e.to_string())?;
+
+    if let Some(modules) = json.get("modules").and_then(|v| v.as_array()) {
+        let mut layout = Vec::new();
+        for module in modules {
+            let kind = match module {
+                serde_json::Value::String(s) => s.as_str(),
+                other => other.get("type").and_then(|t| t.as_str()).unwrap_or(""),
+            };
+            let kind = kind.to_lowercase();
+            match FASTFETCH_MODULES.iter().find(|(t, _)| *t == kind) {
+                Some((_, key)) => push_key(&mut layout, key),
+                None if FASTFETCH_DECORATION.contains(&kind.as_str()) => {}
+                None => m
+                    .notes
+                    .push(format!("module \"{}\": no equivalent module", kind)),
+            }
+        }
+        if !layout.is_empty() {
+            m.config.layout = layout;
+        }
+    }
+
+    let display = json.get("display");
+    if let Some(sep) = display
+        .and_then(|d| d.get("separator"))
+        .and_then(|s| s.as_str())
+    {
+        m.config.general.separator = sep.to_string();
+    }
+    let display_color = |key: &str| {
+        let color = display?.get("color")?;
+        // "color" is either one string for the keys or an object per element
+        let value = match color {
+            serde_json::Value::String(s) if key == "keys" => s.as_str(),
+            other => other.get(key)?.as_str()?,
+        };
+        color::parse_color(value)
+    };
+    set_label_colors(
+        &mut m.config,
+        display_color("keys"),
+        display_color("output"),
+    );
+
+    let logo = json.get("logo");
+    let source = match logo {
+        Some(serde_json::Value::String(s)) => Some(s.as_str()),
+        Some(l) => l.get("source").and_then(|s| s.as_str()),
+        None => None,
+    };
+    if let Some(source) = source.map(str::trim).filter(|s| !s.is_empty()) {
+        let is_file = source.contains('/') || source.contains('.');
+        match logo.and_then(|l| l.get("type")).and_then(|t| t.as_str()) {
+            _ if !is_file => m.config.ascii.distro_override = Some(source.to_lowercase()),
+            Some("file" | "file-raw" | "data" | "data-raw") => {
+                m.config.ascii.logo_path = Some(source.to_string())
+            }
+            _ if source.ends_with(".txt") => m.config.ascii.logo_path = Some(source.to_string()),
+            _ => m.config.general.image_path = Some(source.to_string()),
+        }
+    }
+    if let Some(colors) = logo
+        .and_then(|l| l.get("color"))
+        .and_then(|c| c.as_object())
+    {
+        // Keys "1".."9" are the $1..$9 logo colors
+        let mut numbered: Vec<(u32, [u8; 3])> = colors
+            .iter()
+            .filter_map(|(k, v)| Some((k.parse().ok()?, color::parse_color(v.as_str()?)?)))
+            .collect();
+        numbered.sort_by_key(|&(n, _)| n);
+        set_logo_colors(
+            &mut m.config,
+            numbered.into_iter().map(|(_, c)| c).collect(),
+        );
+    }
+    Ok(())
+}
+
+/// JSONC to JSON: drops `//` and `/* */` comments and trailing commas outside strings.
+fn strip_jsonc(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    let mut in_string = false;
+    while let Some(c) = chars.next() {
+        if in_string {
+            out.push(c);
+            match c {
+                '\\' => out.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                out.push(c);
+            }
+            ('/', Some('/')) => while chars.next_if(|&c| c != '\n').is_some() {},
+            ('/', Some('*')) => {
+                chars.next();
+                let mut prev = ' ';
+                for c in chars.by_ref() {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            (']' | '}', _) => {
+                // A comma followed only by whitespace before the closing bracket
+                let trimmed = out.trim_end().len();
+                if out[..trimmed].ends_with(',') {
+                    out.truncate(trimmed - 1);
+                }
+                out.push(c);
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strip_jsonc_drops_comments_and_trailing_commas() {
+        let text = r#"{
+            // line comment
+            "a": "http://x/*y*/", /* block */
+            "b": [1, 2, /* last */ ],
+        }"#;
+        let json: serde_json::Value = serde_json::from_str(&strip_jsonc(text)).unwrap();
+        assert_eq!(json["a"], "http://x/*y*/");
+        assert_eq!(json["b"], serde_json::json!([1, 2]));
+    }
+
+    #[test]
+    fn shell_words_removes_quotes_and_comments() {
+        assert_eq!(
+            shell_words(r#"  "Kernel"  kernel # trailing"#),
+            ["Kernel", "kernel"]
+        );
+        assert_eq!(shell_words("'a b'c \"\""), ["a bc", ""]);
+    }
+
+    #[test]
+    fn neofetch_print_info_becomes_layout() {
+        let text = r#"
+print_info() {
+    info title
+    info underline
+    info "OS" distro
+    info "Kernel" kernel
+    info "WM" wm
+    info "DE" de
+    info "Battery" battery
+    prin "Hello"
+}
+ascii_distro="Arch"
+image_source="$HOME/logo.png"
+"#;
+        let m = migrate(Source::Neofetch, text).unwrap();
+        assert_eq!(m.config.layout, ["user_host", "os", "kernel", "de"]);
+        assert_eq!(m.config.ascii.distro_override.as_deref(), Some("arch"));
+        assert_eq!(m.config.general.image_path.as_deref(), Some("~/logo.png"));
+        assert_eq!(m.notes.len(), 2);
+    }
+
+    #[test]
+    fn fastfetch_modules_and_logo() {
+        let text = r#"{
+            "logo": { "source": "debian", "color": { "2": "red", "1": "cyan" } },
+            "modules": ["title", "separator", { "type": "CPU" }, "battery",],
+        }"#;
+        let m = migrate(Source::Fastfetch, text).unwrap();
+        assert_eq!(m.config.layout, ["user_host", "cpu"]);
+        assert_eq!(m.config.ascii.distro_override.as_deref(), Some("debian"));
+        assert_eq!(
+            m.config.ascii.colors[0],
+            color::parse_color("cyan").unwrap()
+        );
+        assert_eq!(m.config.ascii.colors.len(), 2);
+        assert_eq!(m.config.theme.mode, "distro");
+        assert_eq!(m.notes, ["module \"battery\": no equivalent module"]);
+    }
+
+    #[test]
+    fn legacy_example_becomes_layout_and_theme() {
+        let text = r#"
+# Toggle which info modules are shown
+show_user_host = true
+show_os = true
+show_kernel = true
+show_uptime = true
+show_shell = true
+show_memory = true
+
+[colors]
+ascii = "cyan"
+key = "yellow"
+value = "white"
+"#;
+        let m = migrate(Source::Legacy, text).unwrap();
+        assert_eq!(
+            m.config.layout,
+            ["user_host", "os", "kernel", "uptime", "shell", "memory"]
+        );
+        assert_eq!(m.config.theme.mode, "distro");
+        assert_eq!(m.config.theme.primary_color, [205, 205, 0]);
+        assert_eq!(m.config.theme.secondary_color, [205, 205, 0]);
+        assert_eq!(m.config.theme.text_color, [229, 229, 229]);
+        assert_eq!(m.config.ascii.colors, [[0, 205, 205]]);
+        assert!(m.notes.is_empty());
+    }
+
+    #[test]
+    fn legacy_keeps_new_format_settings() {
+        let text = r#"
+show_os = true
+show_cpu = true
+
+[general]
+separator = " -> "
+
+[modules.cpu]
+format = "{model}"
+"#;
+        let m = migrate(Source::Legacy, text).unwrap();
+        assert_eq!(m.config.layout, ["os", "cpu"]);
+        assert_eq!(m.config.general.separator, " -> ");
+        assert_eq!(m.config.modules["cpu"].format.as_deref(), Some("{model}"));
+    }
+
+    #[test]
+    fn new_format_config_is_not_migrated() {
+        let text = "layout = [\"os\"]\n\n[theme]\nmode = \"solid\"\n";
+        assert!(migrate(Source::Legacy, text).is_err());
+    }
+
+    #[test]
+    fn detects_source() {
+        assert_eq!(
+            Source::detect(Path::new("config.jsonc"), ""),
+            Source::Fastfetch
+        );
+        assert_eq!(
+            Source::detect(Path::new("config.conf"), ""),
+            Source::Neofetch
+        );
+        assert_eq!(
+            Source::detect(Path::new("config.toml"), "show_os = true"),
+            Source::Legacy
+        );
+    }
+}
@@ -0,0 +1,527 @@
+//! Rice-ready configuration: AppConfig with general, theme, layout, and ascii.
+//! Loads from ~/.config/novafetch/config.toml (XDG) layered over system files and under
+//! profiles, environment and `--set` overrides (see `layers`); generates default if missing.
+//! Errors are reported with their position (see `check`); a broken file is never replaced.
+
+mod check;
+mod layers;
+pub mod migrate;
+
+pub use layers::{Loaded, Overrides};
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_separator() -> String {
+    "  ".into() // Rice-style; e.g. " -> ", " :: ", or "  " (two spaces)
+}
+
+fn default_align_scope() -> String {
+    "section".into()
+}
+
+/// Per-module fetch deadline; slow modules (rpm -qa, MPRIS) are shown as "(timed out)".
+fn default_module_timeout_ms() -> u64 {
+    2000
+}
+
+/// Every built-in module, in registry order.
+fn default_layout() -> Vec<String> {
+    crate::info::registry()
+        .iter()
+        .map(|m| m.key().to_string())
+        .collect()
+}
+
+/// Primary RGB: blue. Used for gradient start / solid labels.
+fn default_primary_color() -> [u8; 3] {
+    [59, 130, 246]
+}
+
+/// Secondary RGB: purple. Used for gradient end.
+fn default_secondary_color() -> [u8; 3] {
+    [147, 51, 234]
+}
+
+/// Text/value color: white.
+fn default_text_color() -> [u8; 3] {
+    [255, 255, 255]
+}
+
+fn default_theme_mode() -> String {
+    "gradient".into()
+}
+
+fn default_display_mode() -> String {
+    "tree".into()
+}
+
+fn default_header_style() -> String {
+    "centered".into()
+}
+
+fn default_icon_set() -> String {
+    "nerd".into()
+}
+
+fn default_image_renderer() -> String {
+    "graphics".into()
+}
+
+fn default_image_colors() -> String {
+    "truecolor".into()
+}
+
+fn default_logo_position() -> String {
+    "left".into()
+}
+
+fn default_logo_margin() -> usize {
+    4
+}
+
+fn default_logo_size() -> String {
+    "auto".into()
+}
+
+fn default_logo_valign() -> String {
+    "top".into()
+}
+
+fn default_header_width() -> usize {
+    24
+}
+
+/// Tree glyphs and headers: orange (xterm 214).
+fn default_tree_color() -> [u8; 3] {
+    [255, 175, 0]
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct GeneralConfig {
+    #[serde(default = "default_separator")]
+    pub separator: String,
+    /// Show label icons (from `icon_set`).
+    #[serde(default = "default_true")]
+    pub use_nerd_fonts: bool,
+    /// Label icons: "nerd" (Nerd Font glyphs), "unicode", "emoji" or "none".
+    #[serde(default = "default_icon_set")]
+    pub icon_set: String,
+    /// Turn Nerd Font icons off when the detected font is not a Nerd Font. Off by default: the
+    /// font comes from the terminal_font module, which reads the desktop monospace font from
+    /// gsettings, not the font set in the terminal's own profile.
+    #[serde(default)]
+    pub detect_nerd_font: bool,
+    #[serde(default = "default_true")]
+    pub align_values: bool,
+    /// Align values per section ("section") or across all sections ("global").
+    #[serde(default = "default_align_scope")]
+    pub align_scope: String,
+    #[serde(default)]
+    pub unit_type: String,
+    #[serde(default = "default_true")]
+    pub show_memory_bar: bool,
+    #[serde(default = "default_true")]
+    pub show_cpu_bar: bool,
+    #[serde(default = "default_true")]
+    pub show_disk_bar: bool,
+    /// Image shown instead of the ASCII logo; a leading `~/` is the home directory.
+    #[serde(default)]
+    pub image_path: Option<String>,
+    #[serde(default)]
+    pub image_width: Option<u32>,
+    /// How `image_path` is drawn: "graphics" (Kitty/iTerm/Sixel via viuer) or as text with
+    /// "blocks", "ascii" or "braille".
+    #[serde(default = "default_image_renderer")]
+    pub image_renderer: String,
+    /// Colors of text-rendered images: "truecolor", "256", "16" or "none".
+    #[serde(default = "default_image_colors")]
+    pub image_colors: String,
+    #[serde(default = "default_module_timeout_ms")]
+    pub module_timeout_ms: u64,
+    /// Per-key overrides of `module_timeout_ms`, e.g. `packages = 5000`.
+    #[serde(default)]
+    pub module_timeouts: BTreeMap<String, u64>,
+}
+
+impl Default for GeneralConfig {
+    fn default() -> Self {
+        Self {
+            separator: default_separator(),
+            use_nerd_fonts: true,
+            icon_set: default_icon_set(),
+            detect_nerd_font: false,
+            align_values: true,
+            align_scope: default_align_scope(),
+            unit_type: "standard".into(),
+            show_memory_bar: true,
+            show_cpu_bar: true,
+            show_disk_bar: true,
+            image_path: None,
+            image_width: None,
+            image_renderer: default_image_renderer(),
+            image_colors: default_image_colors(),
+            module_timeout_ms: default_module_timeout_ms(),
+            module_timeouts: BTreeMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ThemeConfig {
+    #[serde(default = "default_primary_color")]
+    pub primary_color: [u8; 3],
+    #[serde(default = "default_secondary_color")]
+    pub secondary_color: [u8; 3],
+    #[serde(default = "default_text_color")]
+    pub text_color: [u8; 3],
+    /// Label and logo coloring: "gradient", "solid" or "distro" (logo in the distro's palette).
+    #[serde(default = "default_theme_mode")]
+    pub mode: String,
+    /// Take primary/secondary colors from the logo palette so labels match the logo.
+    #[serde(default)]
+    pub colors_from_logo: bool,
+}
+
+impl Default for ThemeConfig {
+    fn default() -> Self {
+        Self {
+            primary_color: default_primary_color(),
+            secondary_color: default_secondary_color(),
+            text_color: default_text_color(),
+            mode: default_theme_mode(),
+            colors_from_logo: false,
+        }
+    }
+}
+
+/// Prefixes of the lines in a tree section and the header fill character.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct TreeGlyphs {
+    /// First line of a section (the "root").
+    pub first: String,
+    pub middle: String,
+    pub last: String,
+    /// Repeated to pad section headers, e.g. "─" or "=".
+    pub fill: String,
+}
+
+impl Default for TreeGlyphs {
+    fn default() -> Self {
+        Self {
+            first: " ".into(),
+            middle: " ├─ ".into(),
+            last: " └─ ".into(),
+            fill: "─".into(),
+        }
+    }
+}
+
+/// One `[[display.sections]]` entry; unset styling falls back to `[display]`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SectionConfig {
+    pub title: String,
+    /// Layout keys shown in this section (in layout order).
+    #[serde(default)]
+    pub modules: Vec<String>,
+    #[serde(default)]
+    pub header_style: Option<String>,
+    #[serde(default)]
+    pub color: Option<[u8; 3]>,
+    #[serde(default)]
+    pub glyphs: Option<TreeGlyphs>,
+}
+
+/// How info lines are arranged: grouped tree sections or flat `label: value` lines.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DisplayConfig {
+    /// "tree" (sections with headers and glyphs) or "flat" (plain lines, neofetch-style).
+    #[serde(default = "default_display_mode")]
+    pub mode: String,
+    /// "centered" (──Title──), "left" (── Title ───), "plain" (Title) or "none".
+    #[serde(default = "default_header_style")]
+    pub header_style: String,
+    #[serde(default = "default_header_width")]
+    pub header_width: usize,
+    /// Color of headers and tree glyphs.
+    #[serde(default = "default_tree_color")]
+    pub color: [u8; 3],
+    #[serde(default)]
+    pub glyphs: TreeGlyphs,
+    /// Blank line after each section.
+    #[serde(default = "default_true")]
+    pub section_gap: bool,
+    /// Ordered sections; empty = Hardware / Software / Status by module category.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sections: Vec<SectionConfig>,
+}
+
+impl Default for DisplayConfig {
+    fn default() -> Self {
+        Self {
+            mode: default_display_mode(),
+            header_style: default_header_style(),
+            header_width: default_header_width(),
+            color: default_tree_color(),
+            glyphs: TreeGlyphs::default(),
+            section_gap: true,
+            sections: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AsciiConfig {
+    #[serde(default)]
+    pub distro_override: Option<String>,
+    #[serde(default = "default_true")]
+    pub print_ascii: bool,
+    /// Logo file to show instead of the distro logo (`~/` is expanded).
+    #[serde(default)]
+    pub logo_path: Option<String>,
+    /// Colors for the `${c1}`..`${c6}` markers of a logo file; default: theme colors.
+    #[serde(default)]
+    pub colors: Vec<[u8; 3]>,
+    /// Where the logo goes relative to the info: "left", "right", "top" or "bottom".
+    #[serde(default = "default_logo_position")]
+    pub position: String,
+    /// Columns between logo and info side by side (stacked output uses one blank line).
+    #[serde(default = "default_logo_margin")]
+    pub margin: usize,
+    /// Columns of indentation before the whole output.
+    #[serde(default)]
+    pub padding: usize,
+    /// Built-in logo size: "auto" (small when the info block is shorter or the terminal
+    /// is narrow), "small" or "large".
+    #[serde(default = "default_logo_size")]
+    pub size: String,
+    /// Vertical alignment of the shorter column side by side: "top", "center" or "bottom".
+    #[serde(default = "default_logo_valign")]
+    pub valign: String,
+}
+
+impl Default for AsciiConfig {
+    fn default() -> Self {
+        Self {
+            distro_override: None,
+            print_ascii: true,
+            logo_path: None,
+            colors: Vec::new(),
+            position: default_logo_position(),
+            margin: default_logo_margin(),
+            padding: 0,
+            size: default_logo_size(),
+            valign: default_logo_valign(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CacheConfig {
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Per-key TTL overrides in seconds, e.g. `packages = 600`.
+    #[serde(default)]
+    pub ttl: BTreeMap<String, u64>,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            ttl: BTreeMap::new(),
+        }
+    }
+}
+
+/// Per-module settings under `[modules.<key>]`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ModuleConfig {
+    /// Value template, e.g. `"{model}[ ({cores}c)] {usage}%[ {temp}]"`; see `info::render_template`.
+    #[serde(default)]
+    pub format: Option<String>,
+    /// Label icon replacing the icon set's glyph; `""` hides the icon.
+    #[serde(default)]
+    pub icon: Option<String>,
+}
+
+/// A user-defined `[[custom]]` module: a command's output (or a file's content) on one line.
+/// The key is usable in `layout` like a built-in module.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CustomModuleConfig {
+    pub key: String,
+    /// Line label; defaults to the key.
+    #[serde(default)]
+    pub label: Option<String>,
+    /// "hardware", "software" or "status" (default).
+    #[serde(default)]
+    pub category: Option<String>,
+    /// Nerd Font icon shown when `use_nerd_fonts` is on.
+    #[serde(default)]
+    pub icon: Option<String>,
+    /// Icon for `icon_set = "unicode"`.
+    #[serde(default)]
+    pub unicode_icon: Option<String>,
+    /// Icon for `icon_set = "emoji"`.
+    #[serde(default)]
+    pub emoji_icon: Option<String>,
+    /// Shell command (`sh -c`); its stdout becomes the value. A non-zero exit shows nothing.
+    #[serde(default)]
+    pub command: Option<String>,
+    /// File to read instead of running a command (`~/` is expanded).
+    #[serde(default)]
+    pub file: Option<String>,
+    /// Deadline in ms; the command is killed when it runs out. Default: `general.module_timeout_ms`.
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+    /// Cache the value for this many seconds (0 or unset = run every time).
+    #[serde(default)]
+    pub cache_ttl: Option<u64>,
+    /// Strip leading/trailing whitespace from the output.
+    #[serde(default = "default_true")]
+    pub trim: bool,
+    /// Keep only the first line of the output.
+    #[serde(default = "default_true")]
+    pub first_line: bool,
+    /// Cut the value to this many characters (adds "…").
+    #[serde(default)]
+    pub max_length: Option<usize>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AppConfig {
+    #[serde(default)]
+    pub general: GeneralConfig,
+    #[serde(default)]
+    pub theme: ThemeConfig,
+    #[serde(default = "default_layout")]
+    pub layout: Vec<String>,
+    #[serde(default)]
+    pub display: DisplayConfig,
+    #[serde(default)]
+    pub ascii: AsciiConfig,
+    #[serde(default)]
+    pub cache: CacheConfig,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub modules: BTreeMap<String, ModuleConfig>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub custom: Vec<CustomModuleConfig>,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            general: GeneralConfig::default(),
+            theme: ThemeConfig::default(),
+            layout: default_layout(),
+            display: DisplayConfig::default(),
+            ascii: AsciiConfig::default(),
+            cache: CacheConfig::default(),
+            modules: BTreeMap::new(),
+            custom: Vec::new(),
+        }
+    }
+}
+
+/// The default config with a comment on every option (`config init`, first run).
+pub const DEFAULT_CONFIG: &str = include_str!("default.toml");
+
+/// Expands a leading `~/` to the home directory.
+pub fn expand_tilde(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => path.into(),
+    }
+}
+
+/// Opens `path` in `$VISUAL` or `$EDITOR` (default: vi) and waits for the editor to exit.
+/// The variable may carry arguments, e.g. `code --wait`.
+pub fn open_in_editor(path: &Path) -> std::io::Result<std::process::ExitStatus> {
+    let editor = ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|e| !e.trim().is_empty())
+        .unwrap_or_else(|| "vi".into());
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().unwrap_or("vi");
+    std::process::Command::new(program)
+        .args(parts)
+        .arg(path)
+        .status()
+}
+
+impl AppConfig {
+    /// Default config path: `~/.config/novafetch/config.toml` (XDG).
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|d| d.join("novafetch").join("config.toml"))
+    }
+
+    /// Fetch deadline for a layout key: `general.module_timeouts` entry, else the `[[custom]]`
+    /// entry's `timeout_ms`, else `general.module_timeout_ms`.
+    pub fn module_timeout(&self, key: &str) -> Duration {
+        let ms = self
+            .general
+            .module_timeouts
+            .get(key)
+            .copied()
+            .or_else(|| {
+                self.custom
+                    .iter()
+                    .find(|c| c.key == key)
+                    .and_then(|c| c.timeout_ms)
+            })
+            .unwrap_or(self.general.module_timeout_ms);
+        Duration::from_millis(ms)
+    }
+
+    /// Load the layered config for the user file `path`. If no config file exists at all, write
+    /// the default there first. Problems are reported on stderr: a layer with a parse error is
+    /// skipped for this run, and no file is ever overwritten.
+    pub fn load(path: Option<&Path>, overrides: &Overrides) -> Self {
+        let Some(path) = path.map(Path::to_path_buf).or_else(Self::default_path) else {
+            return Self::default();
+        };
+        let loaded = layers::load(&path, overrides);
+        // With a system config, a full default file would hide it
+        if loaded.files.is_empty() && !path.exists() {
+            let _ = Self::default().write_to(&path);
+        }
+        for problem in &loaded.problems {
+            eprintln!("novafetch: {}", problem);
+        }
+        loaded.config
+    }
+
+    /// Load the layered config without reporting problems or creating files.
+    pub fn load_layers(path: &Path, overrides: &Overrides) -> Loaded {
+        layers::load(path, overrides)
+    }
+
+    /// Write config to path. Creates parent dirs.
+    pub fn write_to(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, self.to_toml()?)
+    }
+
+    /// TOML text of the config. The default config comes out as the commented template
+    /// (`DEFAULT_CONFIG`), so new files document every option.
+    pub fn to_toml(&self) -> std::io::Result<String> {
+        let serialize = |c: &Self| {
+            toml::to_string(c).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+        };
+        let s = serialize(self)?;
+        if s == serialize(&Self::default())? {
+            return Ok(DEFAULT_CONFIG.to_string());
+        }
+        Ok(s)
+    }
+}
@@ -0,0 +1,376 @@
+//! Module execution: runs the layout's modules in parallel with per-module deadlines,
+//! then formats the structured items into display lines.
+
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, Weak};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use sysinfo::System;
+
+use crate::cache::{Cache, FillLock};
+use crate::config::AppConfig;
+use crate::info::{self, FetchContext, FormatContext, InfoItem, Module};
+
+/// Value shown for a module that did not finish before its deadline.
+pub const TIMEOUT_PLACEHOLDER: &str = "(timed out)";
+
+/// Channel a `collect_lines` call receives results on: (index in its module list, items);
+/// None when the module reported itself timed out (`FetchContext::mark_timed_out`).
+type Reply = Sender<(usize, Option<Vec<InfoItem>>)>;
+
+/// A module fetch that has not finished yet. Later calls (`novafetch serve` scrapes) wait for
+/// it instead of starting another one, so a hung module holds one thread, not one per call.
+struct InFlight {
+    /// Calls waiting for the result; a call that has returned drops its `Reply`.
+    waiting: Vec<(Weak<Reply>, usize)>,
+    /// Set while the module computes a value for the on-disk cache.
+    filling_cache: Arc<AtomicBool>,
+}
+
+/// Running fetches by module key.
+static IN_FLIGHT: Mutex<BTreeMap<&'static str, InFlight>> = Mutex::new(BTreeMap::new());
+
+fn in_flight() -> MutexGuard<'static, BTreeMap<&'static str, InFlight>> {
+    IN_FLIGHT.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// One fetched line in layout order. `item` is None when the module missed its deadline.
+pub struct Line {
+    pub module: &'static dyn Module,
+    pub item: Option<InfoItem>,
+}
+
+/// Builds the sysinfo snapshot the layout needs and collects all lines (see `collect_lines`).
+pub fn fetch_all(config: &Arc<AppConfig>, cache: &Cache) -> Vec<Line> {
+    let sys = snapshot_for(&config.layout).map(Arc::new);
+    collect_lines(config, sys.as_ref(), cache)
+}
+
+/// The sysinfo snapshot the modules `keys` need, if any of them needs one.
+fn snapshot_for(keys: &[String]) -> Option<System> {
+    let refresh = info::refresh_kind_for(keys);
+    let need_sys = refresh.memory().is_some() || refresh.cpu().is_some();
+    let mut sys = need_sys.then(|| info::system_for_fetch(refresh));
+    if let Some(ref mut s) = sys {
+        if refresh.cpu().is_some_and(|c| c.cpu_usage()) {
+            thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+            s.refresh_cpu_usage();
+        }
+    }
+    sys
+}
+
+/// Collect all lines following config.layout.
+/// Each module runs on its own thread with a deadline (`general.module_timeout_ms`,
+/// overridable per key, see `AppConfig::module_timeout`); results are reassembled in layout order
+/// and a module that misses its deadline is shown as a placeholder instead of blocking output.
+/// Unknown layout keys are skipped (config validation reports them).
+pub fn collect_lines(
+    config: &Arc<AppConfig>,
+    sys: Option<&Arc<System>>,
+    cache: &Cache,
+) -> Vec<Line> {
+    let mut modules: Vec<&'static dyn Module> = Vec::new();
+    for key in &config.layout {
+        let key = key.trim();
+        if key.is_empty() {
+            continue;
+        }
+        if let Some(module) = info::find_module(key) {
+            modules.push(module);
+        }
+    }
+    run_modules(config, &modules, sys, cache)
+}
+
+/// Runs `modules` on their own threads and returns their lines in order (see `collect_lines`).
+fn run_modules(
+    config: &Arc<AppConfig>,
+    modules: &[&'static dyn Module],
+    sys: Option<&Arc<System>>,
+    cache: &Cache,
+) -> Vec<Line> {
+    let start = Instant::now();
+    let (tx, rx) = mpsc::channel();
+    // Workers only hold a Weak reference, so results for a call that returned are dropped
+    let reply = Arc::new(tx);
+    for (idx, &module) in modules.iter().enumerate() {
+        let mut running = in_flight();
+        if let Some(fetch) = running.get_mut(module.key()) {
+            fetch.waiting.retain(|(r, _)| r.strong_count() > 0);
+            fetch.waiting.push((Arc::downgrade(&reply), idx));
+            continue;
+        }
+        let filling_cache = Arc::new(AtomicBool::new(false));
+        running.insert(
+            module.key(),
+            InFlight {
+                waiting: vec![(Arc::downgrade(&reply), idx)],
+                filling_cache: Arc::clone(&filling_cache),
+            },
+        );
+        drop(running);
+
+        let config = Arc::clone(config);
+        let sys = sys.cloned();
+        let cache = cache.clone();
+        // Delivers the result when the worker ends, panics or cannot be started
+        let finish = Finish {
+            key: module.key(),
+            items: None,
+            timed_out: false,
+        };
+        let spawned = thread::Builder::new()
+            .name(format!("novafetch-{}", module.key()))
+            .spawn(move || {
+                // Moved as a whole, so it is dropped when the worker ends
+                let mut finish = finish;
+                let timed_out = AtomicBool::new(false);
+                let ctx = FetchContext {
+                    config: &config,
+                    sys: sys.as_deref(),
+                    cache: &cache,
+                    filling_cache: &filling_cache,
+                    timed_out: &timed_out,
+                };
+                finish.items = Some(module.fetch(&ctx));
+                finish.timed_out = timed_out.load(Ordering::Relaxed);
+            });
+        if let Err(e) = spawned {
+            eprintln!(
+                "novafetch: could not start module '{}': {}",
+                module.key(),
+                e
+            );
+        }
+    }
+
+    let deadlines: Vec<Instant> = modules
+        .iter()
+        .map(|m| start + config.module_timeout(m.key()))
+        .collect();
+    let mut results: Vec<Option<Vec<InfoItem>>> = vec![None; modules.len()];
+    let mut timed_out = vec![false; modules.len()];
+    loop {
+        let next_deadline = (0..modules.len())
+            .filter(|&i| results[i].is_none() && !timed_out[i])
+            .map(|i| deadlines[i])
+            .min();
+        let Some(next_deadline) = next_deadline else {
+            break;
+        };
+        match rx.recv_timeout(next_deadline.saturating_duration_since(Instant::now())) {
+            Ok((idx, Some(items))) => {
+                if !timed_out[idx] {
+                    results[idx] = Some(items);
+                }
+            }
+            Ok((idx, None)) => timed_out[idx] = true,
+            Err(RecvTimeoutError::Timeout) => {
+                let now = Instant::now();
+                for i in 0..modules.len() {
+                    if results[i].is_none() && deadlines[i] <= now {
+                        timed_out[i] = true;
+                    }
+                }
+            }
+            // Cannot happen while `reply` is alive
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+
+    let mut lines = Vec::new();
+    for (idx, &module) in modules.iter().enumerate() {
+        match results[idx].take() {
+            Some(items) => {
+                for item in items {
+                    lines.push(Line {
+                        module,
+                        item: Some(item),
+                    });
+                }
+            }
+            None if timed_out[idx] => lines.push(Line { module, item: None }),
+            None => {}
+        }
+    }
+    lines
+}
+
+/// Ends the fetch of `key` when dropped: removes it from `IN_FLIGHT`, sends `items` (nothing
+/// if the module panicked, None if it timed out) to every waiting call.
+struct Finish {
+    key: &'static str,
+    items: Option<Vec<InfoItem>>,
+    timed_out: bool,
+}
+
+impl Drop for Finish {
+    fn drop(&mut self) {
+        let fetch = in_flight().remove(self.key);
+        let items = (!self.timed_out).then(|| self.items.take().unwrap_or_default());
+        for (reply, idx) in fetch.map(|f| f.waiting).unwrap_or_default() {
+            if let Some(reply) = reply.upgrade() {
+                let _ = reply.send((idx, items.clone()));
+            }
+        }
+    }
+}
+
+/// Modules that missed their deadline while computing a cache entry. Their work is lost when
+/// the process exits, so `novafetch --fill-cache` finishes it (see `fill_cache`).
+pub fn unfinished_cache_writes() -> Vec<&'static str> {
+    in_flight()
+        .iter()
+        .filter(|(_, f)| f.filling_cache.load(Ordering::Relaxed))
+        .map(|(&key, _)| key)
+        .collect()
+}
+
+/// Upper deadline of a `--fill-cache` run: modules still running then are abandoned.
+const FILL_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Runs the modules `keys` without the display deadlines (up to `FILL_TIMEOUT`), so their cache
+/// entries are written and the next run finds them. Keys whose fill lock is held by another run
+/// are skipped; a module that is still running at the deadline keeps its lock until it expires.
+pub fn fill_cache(config: &Arc<AppConfig>, keys: &[String], cache: &Cache) {
+    let mut locks: Vec<Option<FillLock>> = Vec::new();
+    let mut modules: Vec<&'static dyn Module> = Vec::new();
+    for module in keys.iter().filter_map(|k| info::find_module(k.trim())) {
+        if let Some(lock) = cache.lock_fill(module.key()) {
+            locks.push(Some(lock));
+            modules.push(module);
+        }
+    }
+    if modules.is_empty() {
+        return;
+    }
+
+    let deadline = Instant::now() + FILL_TIMEOUT;
+    let keys: Vec<String> = modules.iter().map(|m| m.key().to_string()).collect();
+    let sys = snapshot_for(&keys).map(Arc::new);
+    let (tx, rx) = mpsc::channel();
+    for (idx, &module) in modules.iter().enumerate() {
+        let (config, sys, cache, tx) = (Arc::clone(config), sys.clone(), cache.clone(), tx.clone());
+        // Detached, so a hung module does not keep the process alive past the deadline
+        let spawned = thread::Builder::new()
+            .name(format!("novafetch-fill-{}", module.key()))
+            .spawn(move || {
+                let (filling_cache, timed_out) = (AtomicBool::new(false), AtomicBool::new(false));
+                let ctx = FetchContext {
+                    config: &config,
+                    sys: sys.as_deref(),
+                    cache: &cache,
+                    filling_cache: &filling_cache,
+                    timed_out: &timed_out,
+                };
+                module.fetch(&ctx);
+                let _ = tx.send(idx);
+            });
+        if spawned.is_err() {
+            locks[idx] = None;
+        }
+    }
+    drop(tx);
+
+    while locks.iter().any(Option::is_some) {
+        match rx.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+            Ok(idx) => locks[idx] = None,
+            Err(_) => break,
+        }
+    }
+    for lock in locks.into_iter().flatten() {
+        lock.keep();
+    }
+}
+
+/// Turn fetched items into display lines (key, label, value); each module formats its own values,
+/// unless `[modules.<key>] format` sets a template.
+pub fn format_lines(
+    lines: &[Line],
+    config: &AppConfig,
+    no_color: bool,
+) -> Vec<(String, String, String)> {
+    let ctx = FormatContext { config, no_color };
+    lines
+        .iter()
+        .map(|line| {
+            let key = line.module.key().to_string();
+            match &line.item {
+                Some(item) => {
+                    let value = line.module.format(item, &ctx);
+                    let template = config.modules.get(&key).and_then(|m| m.format.as_deref());
+                    let value = match template {
+                        Some(template) => {
+                            let mut values = line.module.template_values(item, &ctx);
+                            values.push(("value".into(), value));
+                            info::render_template(template, &values)
+                        }
+                        None => value,
+                    };
+                    (key, item.label.clone(), value)
+                }
+                None => (
+                    key,
+                    line.module.label().to_string(),
+                    TIMEOUT_PLACEHOLDER.into(),
+                ),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::{CacheMode, CachePolicy};
+    use crate::info::FormatContext;
+    use std::time::Duration;
+
+    /// Computes its cache entry for longer than its deadline.
+    struct SlowCached;
+
+    impl Module for SlowCached {
+        fn key(&self) -> &'static str {
+            "test_slow_cached"
+        }
+
+        fn label(&self) -> &'static str {
+            "Slow"
+        }
+
+        fn category(&self) -> Option<info::Category> {
+            None
+        }
+
+        fn fetch(&self, ctx: &FetchContext) -> Vec<InfoItem> {
+            let policy = CachePolicy::new(Duration::from_secs(60));
+            ctx.cached(self.key(), policy, || {
+                thread::sleep(Duration::from_millis(500))
+            });
+            Vec::new()
+        }
+
+        fn format(&self, _item: &InfoItem, _ctx: &FormatContext) -> String {
+            String::new()
+        }
+    }
+
+    #[test]
+    fn no_cache_writes_are_left_when_the_cache_is_off() {
+        let mut config = AppConfig::default();
+        config
+            .general
+            .module_timeouts
+            .insert("test_slow_cached".into(), 50);
+        let config = Arc::new(config);
+        let cache = Cache::new(CacheMode::Off);
+        let lines = run_modules(&config, &[&SlowCached], None, &cache);
+        // Still running: the line is a timeout placeholder
+        assert!(lines.len() == 1 && lines[0].item.is_none());
+        assert!(unfinished_cache_writes().is_empty());
+    }
+}
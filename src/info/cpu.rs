@@ -1,16 +1,19 @@
 //! CPU model name, optional bar, usage %, frequency, and temperature.
 
-use sysinfo::System;
+use sysinfo::{CpuRefreshKind, RefreshKind, System};
 
 use crate::info::bar;
 use crate::info::components;
+use crate::info::utils;
+
+use super::{field_values, Category, FetchContext, FieldSpec, FormatContext, InfoItem, Module};
 
 fn clean_cpu_name(s: &str) -> String {
     s.split_whitespace().collect::<Vec<_>>().join(" ")
 }
 
-pub fn get(sys: &System, show_bar: bool) -> (String, String) {
-    let name = sys
+pub fn get(sys: &System) -> InfoItem {
+    let model = sys
         .cpus()
         .first()
         .map(|c| {
@@ -23,31 +26,108 @@ pub fn get(sys: &System, show_bar: bool) -> (String, String) {
             }
         })
         .unwrap_or_else(|| "N/A".into());
-    let temp_str = match components::get_temperature(&["k10temp", "coretemp", "package", "die"]) {
-        Some(t) => format!(" ({:.1}°C)", t),
-        None => String::new(),
-    };
-    let name_and_temp = format!("{}{}", name, temp_str);
-
-    if !show_bar {
-        return ("CPU".into(), name_and_temp);
-    }
-
-    let usage_pct = (sys.global_cpu_usage() as u64).min(100);
-    let bar_str = bar::bar(usage_pct, 100, 10);
-    let freq_mhz = sys.cpus().first().map(|c| c.frequency());
-    let freq_str = freq_mhz
-        .map(|mhz| {
-            if mhz >= 1000 {
-                format!("{:.1}GHz", mhz as f32 / 1000.0)
-            } else {
-                format!("{}MHz", mhz)
-            }
-        })
-        .unwrap_or_else(|| "?".into());
-    let value = format!(
-        "{} {}% @ {}  {}",
-        bar_str, usage_pct, freq_str, name_and_temp
-    );
-    ("CPU".into(), value)
+    let temp = components::get_temperature(&["k10temp", "coretemp", "package", "die"]);
+    InfoItem::new("CPU")
+        .with("model", model)
+        .with("cores", sys.cpus().len())
+        .with("usage_percent", sys.global_cpu_usage().clamp(0.0, 100.0))
+        .with_opt("frequency_mhz", sys.cpus().first().map(|c| c.frequency()))
+        .with_opt("temperature_c", temp)
+}
+
+fn usage(item: &InfoItem) -> u64 {
+    item.int("usage_percent").unwrap_or(0).min(100)
+}
+
+fn format_freq(mhz: u64) -> String {
+    if mhz >= 1000 {
+        format!("{:.1}GHz", mhz as f32 / 1000.0)
+    } else {
+        format!("{}MHz", mhz)
+    }
+}
+
+const FIELDS: &[FieldSpec] = &[
+    FieldSpec::text("model", "CPU model name"),
+    FieldSpec::int("cores", "Logical CPU count"),
+    FieldSpec::float("usage_percent", "Global CPU usage (0-100)"),
+    FieldSpec::int("frequency_mhz", "Current frequency of the first core"),
+    FieldSpec::float("temperature_c", "Package temperature in °C"),
+];
+
+pub struct Cpu;
+
+impl Module for Cpu {
+    fn key(&self) -> &'static str {
+        "cpu"
+    }
+
+    fn label(&self) -> &'static str {
+        "CPU"
+    }
+
+    fn category(&self) -> Option<Category> {
+        Some(Category::Hardware)
+    }
+
+    fn icon(&self) -> &'static str {
+        "\u{f0e4}" // fa-microchip
+    }
+
+    fn unicode_icon(&self) -> &'static str {
+        "▦"
+    }
+
+    fn emoji_icon(&self) -> &'static str {
+        "🧠"
+    }
+
+    fn fields(&self) -> &'static [FieldSpec] {
+        FIELDS
+    }
+
+    fn refresh_kind(&self) -> RefreshKind {
+        RefreshKind::nothing().with_cpu(CpuRefreshKind::everything())
+    }
+
+    fn fetch(&self, ctx: &FetchContext) -> Vec<InfoItem> {
+        ctx.sys.map(|s| vec![get(s)]).unwrap_or_default()
+    }
+
+    fn format(&self, item: &InfoItem, ctx: &FormatContext) -> String {
+        let model = item.text("model").unwrap_or("N/A");
+        let name_and_temp = match item.float("temperature_c") {
+            Some(t) => format!("{} ({})", model, utils::format_temp(t)),
+            None => model.to_string(),
+        };
+
+        if !ctx.config.general.show_cpu_bar {
+            return name_and_temp;
+        }
+
+        let usage_pct = usage(item);
+        let bar_str = bar::bar(usage_pct, 100, 10);
+        let freq_str = item
+            .int("frequency_mhz")
+            .map(format_freq)
+            .unwrap_or_else(|| "?".into());
+        format!(
+            "{} {}% @ {}  {}",
+            bar_str, usage_pct, freq_str, name_and_temp
+        )
+    }
+
+    /// Adds `{usage}` (whole %), `{bar}`, `{freq}` ("3.4GHz") and `{temp}` ("52.0°C").
+    fn template_values(&self, item: &InfoItem, _ctx: &FormatContext) -> Vec<(String, String)> {
+        let mut values = field_values(item);
+        values.push(("usage".into(), usage(item).to_string()));
+        values.push(("bar".into(), bar::bar(usage(item), 100, 10)));
+        if let Some(mhz) = item.int("frequency_mhz") {
+            values.push(("freq".into(), format_freq(mhz)));
+        }
+        if let Some(t) = item.float("temperature_c") {
+            values.push(("temp".into(), utils::format_temp(t)));
+        }
+        values
+    }
 }
@@ -0,0 +1,234 @@
+//! User-defined `[[custom]]` modules: one line from a shell command's stdout or a file.
+//! Entries are validated and registered once at startup (`info::register_custom`).
+
+use std::io::Read;
+use std::process::{Child, Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::{Category, FetchContext, FieldSpec, FormatContext, InfoItem, Module};
+use crate::cache::CachePolicy;
+use crate::config::{expand_tilde, CustomModuleConfig};
+
+/// How often a running command is polled for exit.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Commands are killed this long before the module deadline, so they are gone before
+/// novafetch prints and exits.
+const KILL_MARGIN: Duration = Duration::from_millis(50);
+
+/// The command ran past its deadline and was killed.
+struct TimedOut;
+
+const FIELDS: &[FieldSpec] = &[FieldSpec::text(
+    "output",
+    "Command output or file content after trimming",
+)];
+
+pub struct CustomModule {
+    key: &'static str,
+    label: &'static str,
+    icon: &'static str,
+    unicode_icon: &'static str,
+    emoji_icon: &'static str,
+    category: Category,
+    config: CustomModuleConfig,
+}
+
+impl CustomModule {
+    /// Validates a config entry. Strings are leaked: modules live for the whole process.
+    pub fn from_config(config: &CustomModuleConfig) -> Result<Self, String> {
+        let key = config.key.trim();
+        if key.is_empty()
+            || !key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        {
+            return Err(format!(
+                "custom module key '{}' must be non-empty and use only letters, digits, '_' or '-'",
+                config.key
+            ));
+        }
+        if config.command.is_some() == config.file.is_some() {
+            return Err(format!(
+                "custom module '{}' needs exactly one of `command` or `file`",
+                key
+            ));
+        }
+        let category = match config.category.as_deref().map(str::to_lowercase).as_deref() {
+            None | Some("status") => Category::Status,
+            Some("hardware") => Category::Hardware,
+            Some("software") => Category::Software,
+            Some(other) => {
+                return Err(format!(
+                    "custom module '{}': unknown category '{}' (hardware, software, status)",
+                    key, other
+                ))
+            }
+        };
+        let label = config.label.clone().unwrap_or_else(|| key.to_string());
+        Ok(Self {
+            key: Box::leak(key.to_string().into_boxed_str()),
+            label: Box::leak(label.into_boxed_str()),
+            icon: leak(&config.icon),
+            unicode_icon: leak(&config.unicode_icon),
+            emoji_icon: leak(&config.emoji_icon),
+            category,
+            config: config.clone(),
+        })
+    }
+
+    /// None if the command failed or the output is empty after cleaning.
+    fn get(&self, timeout: Duration) -> Result<Option<InfoItem>, TimedOut> {
+        let raw = match (&self.config.command, &self.config.file) {
+            (Some(cmd), _) => run_command(cmd, timeout)?,
+            (None, Some(path)) => std::fs::read_to_string(expand_tilde(path)).ok(),
+            (None, None) => None,
+        };
+        let value = raw.map(|raw| self.clean(&raw)).unwrap_or_default();
+        Ok((!value.is_empty()).then(|| InfoItem::new(self.label).with("output", value)))
+    }
+
+    /// Applies `first_line`, `trim` and `max_length`.
+    fn clean(&self, raw: &str) -> String {
+        let mut s = if self.config.first_line {
+            raw.trim_start_matches(['\n', '\r'])
+                .lines()
+                .next()
+                .unwrap_or("")
+        } else {
+            raw
+        };
+        if self.config.trim {
+            s = s.trim();
+        }
+        match self.config.max_length {
+            Some(max) if s.chars().count() > max => {
+                let cut: String = s.chars().take(max.saturating_sub(1)).collect();
+                format!("{}…", cut)
+            }
+            _ => s.to_string(),
+        }
+    }
+}
+
+/// An optional string as `&'static str` ("" when unset).
+fn leak(s: &Option<String>) -> &'static str {
+    Box::leak(s.clone().unwrap_or_default().into_boxed_str())
+}
+
+/// Runs `cmd` through the platform shell; None on spawn failure or non-zero exit. On timeout
+/// the command and everything it started are killed so they do not outlive the fetch.
+fn run_command(cmd: &str, timeout: Duration) -> Result<Option<String>, TimedOut> {
+    #[cfg(windows)]
+    let mut command = Command::new("cmd");
+    #[cfg(windows)]
+    command.arg("/C");
+    #[cfg(not(windows))]
+    let mut command = Command::new("sh");
+    #[cfg(not(windows))]
+    {
+        use std::os::unix::process::CommandExt;
+        // Own process group, so a timeout kills `sleep` in `sh -c "sleep 5; ..."` too
+        command.arg("-c").process_group(0);
+    }
+
+    let Ok(mut child) = command
+        .arg(cmd)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+    else {
+        return Ok(None);
+    };
+    // Read stdout on a separate thread so a chatty command cannot block on a full pipe
+    let Some(mut stdout) = child.stdout.take() else {
+        return Ok(None);
+    };
+    let reader = thread::spawn(move || {
+        let mut buf = Vec::new();
+        let _ = stdout.read_to_end(&mut buf);
+        String::from_utf8_lossy(&buf).into_owned()
+    });
+
+    let deadline = Instant::now() + timeout.saturating_sub(KILL_MARGIN);
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) if status.success() => return Ok(reader.join().ok()),
+            Ok(Some(_)) => return Ok(None),
+            Ok(None) if Instant::now() >= deadline => {
+                kill_tree(&mut child);
+                return Err(TimedOut);
+            }
+            Ok(None) => thread::sleep(POLL_INTERVAL),
+            Err(_) => return Ok(None),
+        }
+    }
+}
+
+fn kill_tree(child: &mut Child) {
+    #[cfg(not(windows))]
+    {
+        let _ = Command::new("kill")
+            .args(["-KILL", "--", &format!("-{}", child.id())])
+            .stderr(Stdio::null())
+            .status();
+    }
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+impl Module for CustomModule {
+    fn key(&self) -> &'static str {
+        self.key
+    }
+
+    fn label(&self) -> &'static str {
+        self.label
+    }
+
+    fn category(&self) -> Option<Category> {
+        Some(self.category)
+    }
+
+    fn icon(&self) -> &'static str {
+        self.icon
+    }
+
+    fn unicode_icon(&self) -> &'static str {
+        self.unicode_icon
+    }
+
+    fn emoji_icon(&self) -> &'static str {
+        self.emoji_icon
+    }
+
+    fn fields(&self) -> &'static [FieldSpec] {
+        FIELDS
+    }
+
+    fn fetch(&self, ctx: &FetchContext) -> Vec<InfoItem> {
+        let timeout = ctx.config.module_timeout(self.key);
+        // A killed command is reported like any module that missed its deadline (a None is not
+        // cached, so the next run tries again)
+        let get = || {
+            self.get(timeout).unwrap_or_else(|TimedOut| {
+                ctx.mark_timed_out();
+                None
+            })
+        };
+        let item = match self.config.cache_ttl {
+            Some(secs) if secs > 0 => {
+                let policy = CachePolicy::new(Duration::from_secs(secs));
+                ctx.cached_some(self.key, policy, get)
+            }
+            _ => get(),
+        };
+        item.into_iter().collect()
+    }
+
+    fn format(&self, item: &InfoItem, _ctx: &FormatContext) -> String {
+        item.text("output").unwrap_or_default().to_string()
+    }
+}
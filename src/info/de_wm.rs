@@ -2,9 +2,55 @@
 
 use std::env;
 
-pub fn get() -> (String, String) {
+use super::{Category, FetchContext, FieldSpec, FormatContext, InfoItem, Module};
+
+pub fn get() -> InfoItem {
     let de_wm = env::var("XDG_CURRENT_DESKTOP")
         .or_else(|_| env::var("DESKTOP_SESSION"))
-        .unwrap_or_else(|_| "N/A".into());
-    ("DE/WM".into(), de_wm)
+        .ok();
+    InfoItem::new("DE/WM").with_opt("name", de_wm)
+}
+
+const FIELDS: &[FieldSpec] = &[
+    FieldSpec::text("name", "Desktop environment / window manager"),
+];
+
+pub struct DeWm;
+
+impl Module for DeWm {
+    fn key(&self) -> &'static str {
+        "de"
+    }
+
+    fn label(&self) -> &'static str {
+        "DE/WM"
+    }
+
+    fn category(&self) -> Option<Category> {
+        Some(Category::Software)
+    }
+
+    fn icon(&self) -> &'static str {
+        "\u{f1e6}" // fa-desktop
+    }
+
+    fn unicode_icon(&self) -> &'static str {
+        "▣"
+    }
+
+    fn emoji_icon(&self) -> &'static str {
+        "🪟"
+    }
+
+    fn fields(&self) -> &'static [FieldSpec] {
+        FIELDS
+    }
+
+    fn fetch(&self, _ctx: &FetchContext) -> Vec<InfoItem> {
+        vec![get()]
+    }
+
+    fn format(&self, item: &InfoItem, _ctx: &FormatContext) -> String {
+        item.text("name").unwrap_or("N/A").to_string()
+    }
 }
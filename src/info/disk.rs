@@ -6,12 +6,14 @@ use std::path::Path;
 use sysinfo::{DiskKind, DiskRefreshKind, Disks};
 
 use super::utils;
-use super::InfoItem;
+use super::{
+    field_values, usage_values, Category, FetchContext, FieldSpec, FormatContext, InfoItem, Module,
+};
 use crate::info::bar;
 
-/// Returns one (label, value) per relevant disk. Label uses `label_prefix` e.g. "Disk (/)", "Disk (/home)".
-/// `unit_type` controls byte display: "standard" (KB/MB/GB), "iec" (KiB/MiB/GiB), "si" (1000-based).
-pub fn get(show_bar: bool, label_prefix: &str, unit_type: &str) -> Vec<InfoItem> {
+/// Returns one item per relevant disk (mount, fs, used/total bytes).
+/// Label uses `label_prefix` e.g. "Disk (/, ext4)", "Disk (/home, btrfs)".
+pub fn get(label_prefix: &str) -> Vec<InfoItem> {
     let disks = Disks::new_with_refreshed_list_specifics(
         DiskRefreshKind::nothing().with_kind().with_storage(),
     );
@@ -24,50 +26,39 @@ pub fn get(show_bar: bool, label_prefix: &str, unit_type: &str) -> Vec<InfoItem>
         let total = d.total_space();
         let avail = d.available_space();
         let used = total.saturating_sub(avail);
-        let used_str = utils::format_bytes(used, unit_type);
-        let total_str = utils::format_bytes(total, unit_type);
-        let pct = if total > 0 {
-            (used as f64 / total as f64 * 100.0).round() as u32
-        } else {
-            0
-        };
         let fs_type = d.file_system().to_string_lossy();
         let fs_display = if fs_type.is_empty() {
             "?".into()
         } else {
             fs_type.into_owned()
         };
-        let bar_str = if show_bar && total > 0 {
-            bar::bar(used, total, 10)
-        } else {
-            String::new()
-        };
-        let value = if bar_str.is_empty() {
-            format!("{}% ({} / {})", pct, used_str, total_str)
-        } else {
-            format!("{} {}% ({} / {})", bar_str, pct, used_str, total_str)
-        };
         let mount_display = mount_display_string(d.mount_point());
         let label = if label_prefix.is_empty() {
             format!("{} ({})", mount_display, fs_display)
         } else {
             format!("{} ({}, {})", label_prefix, mount_display, fs_display)
         };
-        items.push((label, value));
+        items.push(
+            InfoItem::new(label)
+                .with("mount", mount_display)
+                .with("fs", fs_display)
+                .with("used_bytes", used)
+                .with("total_bytes", total),
+        );
     }
 
     // Stable order: prefer root / and Windows C: first, then sort by mount path
     items.sort_by(|a, b| {
+        let mount = |i: &InfoItem| i.text("mount").unwrap_or_default().to_string();
         let order = |s: &str| {
-            if s.contains("(/)") || s.eq_ignore_ascii_case("C:") || s.contains("(C:)") {
+            if s == "/" || s.eq_ignore_ascii_case("C:") || s.eq_ignore_ascii_case("C:\\") {
                 0
             } else {
                 1
             }
         };
-        let oa = order(&a.0);
-        let ob = order(&b.0);
-        oa.cmp(&ob).then_with(|| a.0.cmp(&b.0))
+        let (ma, mb) = (mount(a), mount(b));
+        order(&ma).cmp(&order(&mb)).then_with(|| ma.cmp(&mb))
     });
 
     items
@@ -111,3 +102,76 @@ fn is_relevant_disk(mount_point: &Path, name: &OsStr, kind: DiskKind) -> bool {
     true
 }
 
+const FIELDS: &[FieldSpec] = &[
+    FieldSpec::text("mount", "Mount point"),
+    FieldSpec::text("fs", "File system type"),
+    FieldSpec::int("used_bytes", "Used space in bytes"),
+    FieldSpec::int("total_bytes", "Total space in bytes"),
+];
+
+pub struct Disk;
+
+impl Module for Disk {
+    fn key(&self) -> &'static str {
+        "disk"
+    }
+
+    fn label(&self) -> &'static str {
+        "Disk"
+    }
+
+    fn category(&self) -> Option<Category> {
+        Some(Category::Hardware)
+    }
+
+    fn icon(&self) -> &'static str {
+        "\u{f0a0}" // fa-hdd-o
+    }
+
+    fn unicode_icon(&self) -> &'static str {
+        "◍"
+    }
+
+    fn emoji_icon(&self) -> &'static str {
+        "💿"
+    }
+
+    fn fields(&self) -> &'static [FieldSpec] {
+        FIELDS
+    }
+
+    fn multi_item(&self) -> bool {
+        true
+    }
+
+    fn fetch(&self, _ctx: &FetchContext) -> Vec<InfoItem> {
+        get("Disk")
+    }
+
+    fn format(&self, item: &InfoItem, ctx: &FormatContext) -> String {
+        // `unit_type` controls byte display: "standard" (KB/MB/GB), "iec" (KiB/MiB/GiB), "si" (1000-based).
+        let general = &ctx.config.general;
+        let used = item.int("used_bytes").unwrap_or(0);
+        let total = item.int("total_bytes").unwrap_or(0);
+        let used_str = utils::format_bytes(used, &general.unit_type);
+        let total_str = utils::format_bytes(total, &general.unit_type);
+        let pct = utils::percent(used, total);
+        let bar_str = if general.show_disk_bar && total > 0 {
+            bar::bar(used, total, 10)
+        } else {
+            String::new()
+        };
+        if bar_str.is_empty() {
+            format!("{}% ({} / {})", pct, used_str, total_str)
+        } else {
+            format!("{} {}% ({} / {})", bar_str, pct, used_str, total_str)
+        }
+    }
+
+    /// Adds `{used}`, `{total}`, `{percent}` and `{bar}`.
+    fn template_values(&self, item: &InfoItem, ctx: &FormatContext) -> Vec<(String, String)> {
+        let mut values = field_values(item);
+        values.extend(usage_values(item, &ctx.config.general.unit_type));
+        values
+    }
+}
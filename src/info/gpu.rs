@@ -3,16 +3,103 @@
 use std::io::Read;
 use std::process::Command;
 
-/// Returns cleaned GPU name(s), or "Generic GPU" on failure. Never panics.
-/// Prefer using `info::gpu()` which calls `get_gpu_name()`.
-#[allow(dead_code)]
-pub fn get() -> (String, String) {
-    let name = get_gpu_name().unwrap_or_else(|| "Generic GPU".into());
-    ("GPU".into(), name)
+use std::time::Duration;
+
+use super::{components, utils};
+use super::{field_values, Category, FetchContext, FieldSpec, FormatContext, InfoItem, Module};
+use crate::cache::CachePolicy;
+
+/// GPU name is detected via lspci; it only changes with hardware.
+const CACHE_TTL: Duration = Duration::from_secs(7 * 24 * 3600);
+
+const FIELDS: &[FieldSpec] = &[
+    FieldSpec::text("name", "GPU model name"),
+    FieldSpec::float("temperature_c", "GPU edge temperature in °C"),
+];
+
+pub struct Gpu;
+
+impl Module for Gpu {
+    fn key(&self) -> &'static str {
+        "gpu"
+    }
+
+    fn label(&self) -> &'static str {
+        "GPU"
+    }
+
+    fn category(&self) -> Option<Category> {
+        Some(Category::Hardware)
+    }
+
+    fn icon(&self) -> &'static str {
+        "\u{f108}" // fa-microchip / display
+    }
+
+    fn unicode_icon(&self) -> &'static str {
+        "▤"
+    }
+
+    fn emoji_icon(&self) -> &'static str {
+        "🎮"
+    }
+
+    fn fields(&self) -> &'static [FieldSpec] {
+        FIELDS
+    }
+
+    fn multi_item(&self) -> bool {
+        true
+    }
+
+    /// One item per GPU, dedicated GPUs first. Enhanced detection (`modules::gpu`) first, then
+    /// OS-specific commands. Detected names are cached (a failed detection is retried next run);
+    /// the temperature is always read live and goes to the first GPU.
+    fn fetch(&self, ctx: &FetchContext) -> Vec<InfoItem> {
+        let names: Vec<String> = ctx
+            .cached_some(self.key(), CachePolicy::new(CACHE_TTL), || {
+                let mut names = crate::modules::gpu::get_gpu_names();
+                if names.is_empty() {
+                    names = get_gpu_names();
+                }
+                (!names.is_empty()).then(|| dedicated_first(names))
+            })
+            .unwrap_or_default();
+        let temperature = components::get_gpu_temperature();
+        if names.is_empty() {
+            return vec![InfoItem::new("GPU").with_opt("temperature_c", temperature)];
+        }
+        names
+            .into_iter()
+            .enumerate()
+            .map(|(i, name)| {
+                InfoItem::new("GPU")
+                    .with("name", name)
+                    .with_opt("temperature_c", temperature.filter(|_| i == 0))
+            })
+            .collect()
+    }
+
+    fn format(&self, item: &InfoItem, _ctx: &FormatContext) -> String {
+        let name = item.text("name").unwrap_or("Generic GPU");
+        match item.float("temperature_c") {
+            Some(t) => format!("{} ({})", name, utils::format_temp(t)),
+            None => name.to_string(),
+        }
+    }
+
+    /// Adds `{temp}` ("52.0°C").
+    fn template_values(&self, item: &InfoItem, _ctx: &FormatContext) -> Vec<(String, String)> {
+        let mut values = field_values(item);
+        if let Some(t) = item.float("temperature_c") {
+            values.push(("temp".into(), utils::format_temp(t)));
+        }
+        values
+    }
 }
 
-/// Detects GPU name(s) using OS-specific commands. Returns None on any failure.
-pub fn get_gpu_name() -> Option<String> {
+/// Detects GPU names using OS-specific commands. Empty on any failure.
+pub fn get_gpu_names() -> Vec<String> {
     #[cfg(target_os = "linux")]
     return gpu_linux();
 
@@ -23,22 +110,24 @@ pub fn get_gpu_name() -> Option<String> {
     return gpu_macos();
 
     #[cfg(not(any(target_os = "linux", target_os = "windows", target_os = "macos")))]
-    None
+    Vec::new()
 }
 
 #[cfg(target_os = "linux")]
-fn gpu_linux() -> Option<String> {
-    if let Some(s) = gpu_linux_lspci() {
-        return Some(crate::info::clean_gpu_name(&s));
-    }
-    if let Some(s) = gpu_linux_sysfs() {
-        return Some(crate::info::clean_gpu_name(&s));
-    }
-    None
+fn gpu_linux() -> Vec<String> {
+    let gpus = gpu_linux_lspci().unwrap_or_default();
+    let gpus = if gpus.is_empty() {
+        gpu_linux_sysfs().into_iter().collect()
+    } else {
+        gpus
+    };
+    gpus.iter()
+        .map(|s| crate::info::clean_gpu_name(s))
+        .collect()
 }
 
 #[cfg(target_os = "linux")]
-fn gpu_linux_lspci() -> Option<String> {
+fn gpu_linux_lspci() -> Option<Vec<String>> {
     let out = Command::new("lspci").arg("-mm").output().ok()?;
     if !out.status.success() {
         return None;
@@ -69,7 +158,7 @@ fn gpu_linux_lspci() -> Option<String> {
             gpus.push(b);
         }
     }
-    prefer_dedicated_or_join(gpus)
+    Some(gpus)
 }
 
 #[cfg(target_os = "linux")]
@@ -90,14 +179,15 @@ fn gpu_linux_sysfs() -> Option<String> {
 }
 
 #[cfg(target_os = "windows")]
-fn gpu_windows() -> Option<String> {
-    let out = Command::new("wmic")
+fn gpu_windows() -> Vec<String> {
+    let Some(out) = Command::new("wmic")
         .args(["path", "win32_videocontroller", "get", "name"])
         .output()
-        .ok()?;
-    if !out.status.success() {
-        return None;
-    }
+        .ok()
+        .filter(|o| o.status.success())
+    else {
+        return Vec::new();
+    };
     let s = String::from_utf8_lossy(&out.stdout);
     let mut names: Vec<String> = Vec::new();
     for line in s.lines() {
@@ -109,29 +199,29 @@ fn gpu_windows() -> Option<String> {
             names.push(line.to_string());
         }
     }
-    prefer_dedicated_or_join(names).map(|s| crate::info::clean_gpu_name(&s))
+    names
+        .iter()
+        .map(|s| crate::info::clean_gpu_name(s))
+        .collect()
 }
 
 #[cfg(target_os = "macos")]
-fn gpu_macos() -> Option<String> {
-    let out = Command::new("system_profiler")
+fn gpu_macos() -> Vec<String> {
+    let Some(out) = Command::new("system_profiler")
         .arg("SPDisplaysDataType")
         .output()
-        .ok()?;
-    if !out.status.success() {
-        return None;
-    }
+        .ok()
+        .filter(|o| o.status.success())
+    else {
+        return Vec::new();
+    };
     let s = String::from_utf8_lossy(&out.stdout);
-    for line in s.lines() {
-        let line = line.trim();
-        if line.starts_with("Chipset Model:") {
-            let name = line.strip_prefix("Chipset Model:").unwrap_or("").trim();
-            if !name.is_empty() {
-                return Some(crate::info::clean_gpu_name(name));
-            }
-        }
-    }
-    None
+    s.lines()
+        .filter_map(|line| line.trim().strip_prefix("Chipset Model:"))
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(crate::info::clean_gpu_name)
+        .collect()
 }
 
 fn read_file_trim(path: &str) -> Option<String> {
@@ -146,19 +236,16 @@ fn read_file_trim(path: &str) -> Option<String> {
     }
 }
 
-/// Prefer dedicated GPU; if multiple, join with ", ".
-fn prefer_dedicated_or_join(mut gpus: Vec<String>) -> Option<String> {
-    if gpus.is_empty() {
-        return None;
-    }
+/// GPUs without duplicates, dedicated ones before integrated ones.
+fn dedicated_first(mut gpus: Vec<String>) -> Vec<String> {
     let integrated = ["Intel UHD", "Intel HD", "Intel Graphics", "AMD Radeon Graphics", "Mesa"];
-    let dedicated = gpus
-        .iter()
-        .find(|s| !integrated.iter().any(|i| s.contains(i)));
-    if let Some(d) = dedicated {
-        return Some(d.clone());
-    }
-    gpus.dedup();
-    Some(gpus.join(", "))
+    let mut seen = Vec::new();
+    gpus.retain(|s| {
+        let new = !seen.contains(s);
+        seen.push(s.clone());
+        new
+    });
+    gpus.sort_by_key(|s| integrated.iter().any(|i| s.contains(i)));
+    gpus
 }
 
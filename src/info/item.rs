@@ -0,0 +1,156 @@
+//! Structured module output: a label plus named, typed fields.
+//! Modules fill fields with raw numbers (bytes, MHz, °C, seconds); `Module::format` turns them
+//! into the display string, so machine outputs (JSON) keep the real values.
+
+use serde::{Deserialize, Serialize};
+
+/// One typed field value. Units live in the field name (`used_bytes`, `temperature_c`, ...).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Field {
+    Int(u64),
+    Float(f64),
+    Text(String),
+}
+
+impl From<u64> for Field {
+    fn from(v: u64) -> Self {
+        Field::Int(v)
+    }
+}
+
+impl From<u32> for Field {
+    fn from(v: u32) -> Self {
+        Field::Int(u64::from(v))
+    }
+}
+
+impl From<usize> for Field {
+    fn from(v: usize) -> Self {
+        Field::Int(v as u64)
+    }
+}
+
+impl From<f32> for Field {
+    fn from(v: f32) -> Self {
+        // Round-trip through the shortest decimal so 43.9f32 becomes 43.9, not 43.900001525878906
+        Field::Float(v.to_string().parse().unwrap_or(f64::from(v)))
+    }
+}
+
+impl From<f64> for Field {
+    fn from(v: f64) -> Self {
+        Field::Float(v)
+    }
+}
+
+impl From<String> for Field {
+    fn from(v: String) -> Self {
+        Field::Text(v)
+    }
+}
+
+impl From<&str> for Field {
+    fn from(v: &str) -> Self {
+        Field::Text(v.to_string())
+    }
+}
+
+/// Type of a field as published in the JSON Schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    Int,
+    Float,
+    Text,
+}
+
+/// Static description of one field a module may emit (absent fields are allowed).
+pub struct FieldSpec {
+    pub name: &'static str,
+    pub kind: FieldKind,
+    pub description: &'static str,
+}
+
+impl FieldSpec {
+    pub const fn int(name: &'static str, description: &'static str) -> Self {
+        Self {
+            name,
+            kind: FieldKind::Int,
+            description,
+        }
+    }
+
+    pub const fn float(name: &'static str, description: &'static str) -> Self {
+        Self {
+            name,
+            kind: FieldKind::Float,
+            description,
+        }
+    }
+
+    pub const fn text(name: &'static str, description: &'static str) -> Self {
+        Self {
+            name,
+            kind: FieldKind::Text,
+            description,
+        }
+    }
+}
+
+/// One line of fetch output: display label + ordered fields.
+/// Empty label = header line (e.g. user@host); the module's default label is used instead.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InfoItem {
+    pub label: String,
+    pub fields: Vec<(String, Field)>,
+}
+
+impl InfoItem {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// Adds a field (builder style).
+    pub fn with(mut self, name: &str, value: impl Into<Field>) -> Self {
+        self.fields.push((name.to_string(), value.into()));
+        self
+    }
+
+    /// Adds a field only when `value` is Some; missing data is simply absent.
+    pub fn with_opt<T: Into<Field>>(self, name: &str, value: Option<T>) -> Self {
+        match value {
+            Some(v) => self.with(name, v),
+            None => self,
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Field> {
+        self.fields.iter().find(|(n, _)| n == name).map(|(_, v)| v)
+    }
+
+    pub fn text(&self, name: &str) -> Option<&str> {
+        match self.get(name)? {
+            Field::Text(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn int(&self, name: &str) -> Option<u64> {
+        match self.get(name)? {
+            Field::Int(n) => Some(*n),
+            Field::Float(f) => Some(*f as u64),
+            Field::Text(_) => None,
+        }
+    }
+
+    pub fn float(&self, name: &str) -> Option<f64> {
+        match self.get(name)? {
+            Field::Int(n) => Some(*n as f64),
+            Field::Float(f) => Some(*f),
+            Field::Text(_) => None,
+        }
+    }
+}
@@ -1,6 +1,50 @@
 use sysinfo::System;
 
-pub fn get() -> (String, String) {
-    let kernel = System::kernel_long_version();
-    ("Kernel".into(), kernel)
+use super::{Category, FetchContext, FieldSpec, FormatContext, InfoItem, Module};
+
+pub fn get() -> InfoItem {
+    InfoItem::new("Kernel").with("version", System::kernel_long_version())
+}
+
+const FIELDS: &[FieldSpec] = &[
+    FieldSpec::text("version", "Kernel version string"),
+];
+
+pub struct Kernel;
+
+impl Module for Kernel {
+    fn key(&self) -> &'static str {
+        "kernel"
+    }
+
+    fn label(&self) -> &'static str {
+        "Kernel"
+    }
+
+    fn category(&self) -> Option<Category> {
+        Some(Category::Software)
+    }
+
+    fn icon(&self) -> &'static str {
+        "\u{f109}" // fa-cog
+    }
+
+    fn unicode_icon(&self) -> &'static str {
+        "⚙"
+    }
+
+    fn emoji_icon(&self) -> &'static str {
+        "🔧"
+    }
+
+    fn fields(&self) -> &'static [FieldSpec] {
+        FIELDS
+    }
+
+    fn fetch(&self, _ctx: &FetchContext) -> Vec<InfoItem> {
+        vec![get()]
+    }
+    fn format(&self, item: &InfoItem, _ctx: &FormatContext) -> String {
+        item.text("version").unwrap_or("unknown").to_string()
+    }
 }
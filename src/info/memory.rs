@@ -1,27 +1,94 @@
-use sysinfo::System;
+use sysinfo::{MemoryRefreshKind, RefreshKind, System};
 
 use crate::info::bar;
 use crate::info::components;
 use crate::info::utils;
 
-pub fn get(sys: &System, show_bar: bool, unit_type: &str) -> (String, String) {
-    let used = sys.used_memory();
-    let total = sys.total_memory();
-    let used_str = utils::format_bytes(used, unit_type);
-    let total_str = utils::format_bytes(total, unit_type);
-    let bar_str = if show_bar && total > 0 {
-        bar::bar(used, total, 10)
-    } else {
-        String::new()
-    };
-    let base = if bar_str.is_empty() {
-        format!("{} / {}", used_str, total_str)
-    } else {
-        format!("{} {} / {}", bar_str, used_str, total_str)
-    };
-    let value = match components::get_ram_temperature() {
-        Some(t) => format!("{} ({:.1}°C)", base, t),
-        None => base,
-    };
-    ("Memory".into(), value)
+use super::{
+    field_values, usage_values, Category, FetchContext, FieldSpec, FormatContext, InfoItem, Module,
+};
+
+pub fn get(sys: &System) -> InfoItem {
+    InfoItem::new("Memory")
+        .with("used_bytes", sys.used_memory())
+        .with("total_bytes", sys.total_memory())
+        .with_opt("temperature_c", components::get_ram_temperature())
+}
+
+const FIELDS: &[FieldSpec] = &[
+    FieldSpec::int("used_bytes", "Used RAM in bytes"),
+    FieldSpec::int("total_bytes", "Total RAM in bytes"),
+    FieldSpec::float("temperature_c", "Average DIMM temperature in °C"),
+];
+
+pub struct Memory;
+
+impl Module for Memory {
+    fn key(&self) -> &'static str {
+        "memory"
+    }
+
+    fn label(&self) -> &'static str {
+        "Memory"
+    }
+
+    fn category(&self) -> Option<Category> {
+        Some(Category::Hardware)
+    }
+
+    fn icon(&self) -> &'static str {
+        "\u{f2db}" // fa-memory
+    }
+
+    fn unicode_icon(&self) -> &'static str {
+        "▥"
+    }
+
+    fn emoji_icon(&self) -> &'static str {
+        "💾"
+    }
+
+    fn fields(&self) -> &'static [FieldSpec] {
+        FIELDS
+    }
+
+    fn refresh_kind(&self) -> RefreshKind {
+        RefreshKind::nothing().with_memory(MemoryRefreshKind::nothing().with_ram())
+    }
+
+    fn fetch(&self, ctx: &FetchContext) -> Vec<InfoItem> {
+        ctx.sys.map(|s| vec![get(s)]).unwrap_or_default()
+    }
+
+    fn format(&self, item: &InfoItem, ctx: &FormatContext) -> String {
+        let general = &ctx.config.general;
+        let used = item.int("used_bytes").unwrap_or(0);
+        let total = item.int("total_bytes").unwrap_or(0);
+        let used_str = utils::format_bytes(used, &general.unit_type);
+        let total_str = utils::format_bytes(total, &general.unit_type);
+        let bar_str = if general.show_memory_bar && total > 0 {
+            bar::bar(used, total, 10)
+        } else {
+            String::new()
+        };
+        let base = if bar_str.is_empty() {
+            format!("{} / {}", used_str, total_str)
+        } else {
+            format!("{} {} / {}", bar_str, used_str, total_str)
+        };
+        match item.float("temperature_c") {
+            Some(t) => format!("{} ({})", base, utils::format_temp(t)),
+            None => base,
+        }
+    }
+
+    /// Adds `{used}`, `{total}`, `{percent}`, `{bar}` and `{temp}`.
+    fn template_values(&self, item: &InfoItem, ctx: &FormatContext) -> Vec<(String, String)> {
+        let mut values = field_values(item);
+        values.extend(usage_values(item, &ctx.config.general.unit_type));
+        if let Some(t) = item.float("temperature_c") {
+            values.push(("temp".into(), utils::format_temp(t)));
+        }
+        values
+    }
 }
@@ -1,18 +1,23 @@
 mod bar;
 mod components;
 mod cpu;
+mod custom;
 mod de_wm;
 mod disk;
 mod gpu;
+mod item;
 mod kernel;
 mod memory;
+mod module;
 mod os;
 mod os_age;
+mod os_release;
 mod packages;
 mod palette;
 mod resolution;
 mod shell;
 mod swap;
+mod template;
 mod terminal;
 mod terminal_font;
 mod theme;
@@ -21,115 +26,120 @@ mod user_host;
 mod utils;
 
 pub use bar::bar;
+pub use item::{Field, FieldKind, FieldSpec, InfoItem};
+pub use module::{Category, FetchContext, FormatContext, Module};
+pub use os_release::OsRelease;
+pub use template::{field_values, render_template, usage_values};
+use std::sync::OnceLock;
 use sysinfo::{CpuRefreshKind, MemoryRefreshKind, RefreshKind, System};
 
-/// One line of fetch output: (key, value). Empty key = header line (e.g. user@host).
-pub type InfoItem = (String, String);
-
-pub fn user_host() -> InfoItem {
-    user_host::get()
-}
-
-pub fn os() -> InfoItem {
-    os::get()
-}
-
-pub fn kernel() -> InfoItem {
-    kernel::get()
-}
-
-pub fn uptime() -> InfoItem {
-    uptime::get()
-}
-
-pub fn shell() -> InfoItem {
-    shell::get()
-}
-
-pub fn de_wm() -> InfoItem {
-    de_wm::get()
-}
-
-pub fn cpu(sys: &System, show_bar: bool) -> InfoItem {
-    cpu::get(sys, show_bar)
-}
-
-/// Raw GPU name detection (OS-specific commands). Returns None on failure.
-pub fn get_gpu_name() -> Option<String> {
-    gpu::get_gpu_name()
-}
-
-/// GPU temperature from sysinfo Components (prefers edge/composite over junction). None if unavailable.
-pub fn get_gpu_temperature() -> Option<f32> {
-    components::get_gpu_temperature()
-}
-
-pub fn gpu() -> InfoItem {
-    let gpu_name = get_gpu_name().unwrap_or_else(|| "Generic GPU".into());
-    let value = match get_gpu_temperature() {
-        Some(t) => format!("{} ({:.1}°C)", gpu_name, t),
-        None => gpu_name,
-    };
-    ("GPU".into(), value)
-}
-
-pub fn memory(sys: &System, show_bar: bool, unit_type: &str) -> InfoItem {
-    memory::get(sys, show_bar, unit_type)
-}
-
-pub fn disk(show_bar: bool, label_prefix: &str, unit_type: &str) -> Vec<InfoItem> {
-    disk::get(show_bar, label_prefix, unit_type)
-}
-
-pub fn terminal() -> InfoItem {
-    terminal::get()
-}
-
-pub fn terminal_font() -> InfoItem {
-    terminal_font::get()
-}
-
-pub fn packages() -> InfoItem {
-    packages::get()
-}
-
-pub fn resolution() -> InfoItem {
-    resolution::get()
-}
-
-pub fn swap(sys: &System, unit_type: &str) -> InfoItem {
-    swap::get(sys, unit_type)
-}
-
-pub fn os_age() -> InfoItem {
-    os_age::get()
-}
-
-/// GTK theme, icon theme, and font from ~/.config/gtk-3.0/settings.ini.
-/// Returns one (label, value) per line: Theme, Icons, Font.
-pub fn theme() -> Vec<InfoItem> {
-    theme::get_theme_info()
-}
-
-/// ANSI color palette rows (8 standard + 8 bright blocks). When no_color, returns plain blocks.
-/// Returns Vec with two strings: [normal_colors, bright_colors].
-pub fn get_color_palette(no_color: bool) -> Vec<String> {
-    palette::get_color_palette(no_color)
+use crate::config::CustomModuleConfig;
+
+/// All built-in modules, in default layout order.
+static MODULES: &[&dyn Module] = &[
+    &user_host::UserHost,
+    &os::Os,
+    &kernel::Kernel,
+    &uptime::Uptime,
+    &shell::Shell,
+    &de_wm::DeWm,
+    &cpu::Cpu,
+    &gpu::Gpu,
+    &memory::Memory,
+    &disk::Disk,
+    &terminal::Terminal,
+    &terminal_font::TerminalFont,
+    &packages::Packages,
+    &resolution::Resolution,
+    &swap::Swap,
+    &os_age::OsAge,
+    &theme::Theme,
+    &crate::modules::media::Media,
+    &crate::modules::net::LocalIp,
+    &palette::Palette,
+];
+
+/// Registry of all built-in modules, in default layout order.
+pub fn registry() -> &'static [&'static dyn Module] {
+    MODULES
+}
+
+/// `[[custom]]` modules from the config, set once by `register_custom`.
+static CUSTOM_MODULES: OnceLock<Vec<&'static dyn Module>> = OnceLock::new();
+
+/// Validates and registers the config's `[[custom]]` modules so `find_module` resolves their keys.
+/// Invalid entries and keys clashing with a built-in or earlier entry are reported and skipped.
+/// Only the first call has an effect.
+pub fn register_custom(entries: &[CustomModuleConfig]) {
+    let mut modules: Vec<&'static dyn Module> = Vec::new();
+    for entry in entries {
+        match custom::CustomModule::from_config(entry) {
+            Ok(m)
+                if find_module(m.key()).is_some()
+                    || modules.iter().any(|o| o.key() == m.key()) =>
+            {
+                eprintln!("novafetch: custom module key '{}' is already taken", m.key());
+            }
+            Ok(m) => modules.push(Box::leak(Box::new(m))),
+            Err(e) => eprintln!("novafetch: {}", e),
+        }
+    }
+    let _ = CUSTOM_MODULES.set(modules);
+}
+
+/// Looks up a built-in or custom module by its layout key. None = unknown key.
+pub fn find_module(key: &str) -> Option<&'static dyn Module> {
+    MODULES
+        .iter()
+        .chain(CUSTOM_MODULES.get().into_iter().flatten())
+        .copied()
+        .find(|m| m.key() == key)
+}
+
+/// Union of the sysinfo refresh kinds required by the given layout keys.
+pub fn refresh_kind_for(keys: &[String]) -> RefreshKind {
+    keys.iter()
+        .filter_map(|k| find_module(k.trim()))
+        .map(|m| m.refresh_kind())
+        .fold(RefreshKind::nothing(), merge_refresh_kind)
+}
+
+fn merge_refresh_kind(acc: RefreshKind, other: RefreshKind) -> RefreshKind {
+    let mut out = acc;
+    if let Some(m) = other.memory() {
+        let mut merged = out.memory().unwrap_or_else(MemoryRefreshKind::nothing);
+        if m.ram() {
+            merged = merged.with_ram();
+        }
+        if m.swap() {
+            merged = merged.with_swap();
+        }
+        out = out.with_memory(merged);
+    }
+    if let Some(c) = other.cpu() {
+        let mut merged = out.cpu().unwrap_or_else(CpuRefreshKind::nothing);
+        if c.cpu_usage() {
+            merged = merged.with_cpu_usage();
+        }
+        if c.frequency() {
+            merged = merged.with_frequency();
+        }
+        out = out.with_cpu(merged);
+    }
+    out
 }
 
-/// Builds a System with only memory and CPU (name) refreshed for performance.
-pub fn system_for_fetch() -> System {
-    System::new_with_specifics(
-        RefreshKind::nothing()
-            .with_memory(MemoryRefreshKind::everything())
-            .with_cpu(CpuRefreshKind::everything()),
-    )
+/// Builds a System with only the given refresh kinds (see `refresh_kind_for`) for performance.
+pub fn system_for_fetch(refresh: RefreshKind) -> System {
+    System::new_with_specifics(refresh)
 }
 
-/// Detected distribution ID from the OS (e.g. "arch", "ubuntu").
-/// Used for logo selection when no --logo override is given.
+/// Detected distribution ID (e.g. "arch", "ubuntu"): os-release `ID`, else sysinfo's guess.
+/// Logo selection starts from it (see `ui::logos::detect_logo`).
 pub fn distro_slug() -> String {
-    System::distribution_id()
+    OsRelease::get()
+        .and_then(|r| r.id.clone())
+        .unwrap_or_else(System::distribution_id)
 }
 
 /// Universal GPU name cleaner for lspci-style raw strings (AMD, NVIDIA, Intel).
@@ -0,0 +1,154 @@
+//! Module trait: one implementation per layout key (user_host, cpu, disk, ...).
+//! The registry in `info::mod` is the single source for keys, labels, categories and icons.
+//! Fetching (structured `InfoItem`s) and formatting (display strings) are separate steps.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use sysinfo::{RefreshKind, System};
+
+use crate::cache::{Cache, CachePolicy};
+use crate::config::AppConfig;
+
+use super::{field_values, FieldSpec, InfoItem};
+
+/// Default tree section of a module (see `ui::tree`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    Hardware,
+    Software,
+    Status,
+}
+
+impl Category {
+    pub fn name(&self) -> &str {
+        match self {
+            Category::Hardware => "Hardware",
+            Category::Software => "Software",
+            Category::Status => "Status",
+        }
+    }
+}
+
+/// Everything a module may need while fetching. `sys` is refreshed with the union of
+/// `Module::refresh_kind` over all modules in the layout (None if no module needs it).
+pub struct FetchContext<'a> {
+    pub config: &'a AppConfig,
+    pub sys: Option<&'a System>,
+    pub cache: &'a Cache,
+    /// Set while a cache miss is being computed (see `fetch::unfinished_cache_writes`).
+    pub filling_cache: &'a AtomicBool,
+    /// Set by a module that gave up before its deadline (see `FetchContext::mark_timed_out`).
+    pub timed_out: &'a AtomicBool,
+}
+
+impl FetchContext<'_> {
+    /// Runs `compute` through the on-disk cache under `key`.
+    /// A `[cache.ttl]` entry for the key (seconds) overrides the module's default TTL.
+    pub fn cached<T, F>(&self, key: &str, policy: CachePolicy, compute: F) -> T
+    where
+        T: Serialize + DeserializeOwned,
+        F: FnOnce() -> T,
+    {
+        self.cache
+            .get_or_insert(key, &self.policy(key, policy), || {
+                self.mark_filling_cache();
+                compute()
+            })
+    }
+
+    /// Like `cached`, but a `None` result is not stored (see `Cache::get_or_try_insert`).
+    pub fn cached_some<T, F>(&self, key: &str, policy: CachePolicy, compute: F) -> Option<T>
+    where
+        T: Serialize + DeserializeOwned,
+        F: FnOnce() -> Option<T>,
+    {
+        self.cache
+            .get_or_try_insert(key, &self.policy(key, policy), || {
+                self.mark_filling_cache();
+                compute()
+            })
+    }
+
+    /// Flags a cache miss being computed; nothing is written when the cache is off.
+    fn mark_filling_cache(&self) {
+        if self.cache.writable() {
+            self.filling_cache.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Reports the fetch as timed out: its items are discarded and the line is shown like a
+    /// module that missed its deadline (`null` and listed in `timed_out` in machine outputs).
+    pub fn mark_timed_out(&self) {
+        self.timed_out.store(true, Ordering::Relaxed);
+    }
+
+    fn policy(&self, key: &str, mut policy: CachePolicy) -> CachePolicy {
+        if let Some(&secs) = self.config.cache.ttl.get(key) {
+            policy.ttl = Duration::from_secs(secs);
+        }
+        policy
+    }
+}
+
+/// Everything a module may need to turn an item into a display string.
+pub struct FormatContext<'a> {
+    pub config: &'a AppConfig,
+    pub no_color: bool,
+}
+
+/// A fetch module. Implementors are unit structs listed in `info::registry()`.
+pub trait Module: Sync {
+    /// Layout key used in `config.layout` (e.g. "cpu").
+    fn key(&self) -> &'static str;
+
+    /// Label used when an item comes back with an empty label (e.g. "Host" for user_host).
+    fn label(&self) -> &'static str;
+
+    /// Tree category; None = not grouped (palette is printed at the very end).
+    fn category(&self) -> Option<Category>;
+
+    /// Nerd Font icon (Unicode private use). Empty string = no icon.
+    fn icon(&self) -> &'static str {
+        ""
+    }
+
+    /// Single-width symbol for `icon_set = "unicode"` (common monospace fonts have it).
+    fn unicode_icon(&self) -> &'static str {
+        ""
+    }
+
+    /// Emoji with default emoji presentation for `icon_set = "emoji"`.
+    fn emoji_icon(&self) -> &'static str {
+        ""
+    }
+
+    /// Fields this module's items may carry (documents the JSON Schema).
+    fn fields(&self) -> &'static [FieldSpec] {
+        &[]
+    }
+
+    /// True if the module yields one item per device (disks, displays, GPUs);
+    /// machine outputs then use an array instead of a single object.
+    fn multi_item(&self) -> bool {
+        false
+    }
+
+    /// sysinfo data this module reads from `FetchContext::sys`.
+    fn refresh_kind(&self) -> RefreshKind {
+        RefreshKind::nothing()
+    }
+
+    /// Returns one item per output line. Empty vec = module shows nothing.
+    fn fetch(&self, ctx: &FetchContext) -> Vec<InfoItem>;
+
+    /// Display string for one of this module's items (bars, units, °C).
+    fn format(&self, item: &InfoItem, ctx: &FormatContext) -> String;
+
+    /// Named values for a `format` template: the raw fields plus display-ready helpers
+    /// (`{bar}`, `{used}`, `{temp}`, ...). `{value}` (the default display string) is always added.
+    fn template_values(&self, item: &InfoItem, _ctx: &FormatContext) -> Vec<(String, String)> {
+        field_values(item)
+    }
+}
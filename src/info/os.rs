@@ -1,18 +1,89 @@
 use sysinfo::System;
 
-pub fn get() -> (String, String) {
-    let os = System::long_os_version()
-        .or_else(|| {
-            System::name().and_then(|name| {
-                System::os_version().map(|ver| format!("{} {}", name, ver))
+use super::{Category, FetchContext, FieldSpec, FormatContext, InfoItem, Module, OsRelease};
+
+/// OS name from os-release (`PRETTY_NAME`, else `NAME VERSION_ID`), else from sysinfo.
+pub fn get() -> InfoItem {
+    let release = OsRelease::get();
+    let os = release
+        .and_then(|r| {
+            r.pretty_name.clone().or_else(|| {
+                r.name.as_ref().map(|name| match &r.version_id {
+                    Some(ver) => format!("{} {}", name, ver),
+                    None => name.clone(),
+                })
             })
         })
+        .or_else(System::long_os_version)
+        .or_else(|| {
+            System::name()
+                .and_then(|name| System::os_version().map(|ver| format!("{} {}", name, ver)))
+        })
         .unwrap_or_else(|| System::name().unwrap_or_else(|| "unknown".into()));
     let arch = System::cpu_arch();
-    let full = if arch.is_empty() || os.to_lowercase().contains(&arch.to_lowercase()) {
-        os
-    } else {
-        format!("{} {}", os, arch)
-    };
-    ("OS".into(), full)
+    InfoItem::new("OS")
+        .with("name", os)
+        .with_opt("id", release.and_then(|r| r.id.clone()))
+        .with_opt("codename", release.and_then(|r| r.version_codename.clone()))
+        .with_opt("arch", (!arch.is_empty()).then_some(arch))
+}
+
+const FIELDS: &[FieldSpec] = &[
+    FieldSpec::text("name", "Distribution name and version"),
+    FieldSpec::text("id", "Distribution ID from os-release, e.g. debian"),
+    FieldSpec::text(
+        "codename",
+        "Release codename from os-release, e.g. bookworm",
+    ),
+    FieldSpec::text("arch", "CPU architecture, e.g. x86_64"),
+];
+
+pub struct Os;
+
+impl Module for Os {
+    fn key(&self) -> &'static str {
+        "os"
+    }
+
+    fn label(&self) -> &'static str {
+        "OS"
+    }
+
+    fn category(&self) -> Option<Category> {
+        Some(Category::Software)
+    }
+
+    fn icon(&self) -> &'static str {
+        "\u{f17c}" // fa-linux
+    }
+
+    fn unicode_icon(&self) -> &'static str {
+        "◆"
+    }
+
+    fn emoji_icon(&self) -> &'static str {
+        "💻"
+    }
+
+    fn fields(&self) -> &'static [FieldSpec] {
+        FIELDS
+    }
+
+    fn fetch(&self, _ctx: &FetchContext) -> Vec<InfoItem> {
+        vec![get()]
+    }
+    fn format(&self, item: &InfoItem, _ctx: &FormatContext) -> String {
+        let mut os = item.text("name").unwrap_or("unknown").to_string();
+        if let Some(codename) = item.text("codename") {
+            if !os.to_lowercase().contains(&codename.to_lowercase()) {
+                os = format!("{} ({})", os, codename);
+            }
+        }
+        match item.text("arch") {
+            Some(arch) if !os.to_lowercase().contains(&arch.to_lowercase()) => {
+                format!("{} {}", os, arch)
+            }
+            _ => os,
+        }
+    }
 }
@@ -1,22 +1,29 @@
 //! OS install age: approximate "X years, Y months, Z days" from root or installer dir birth time.
 
-#[cfg(target_os = "linux")]
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::{field_values, Category, FetchContext, FieldSpec, FormatContext, InfoItem, Module};
+use crate::cache::CachePolicy;
 
-/// Returns OS age string, e.g. "2 years, 3 months, 4 days" or "Unknown".
-pub fn get() -> (String, String) {
-    let value = detect_os_age().unwrap_or_else(|| "Unknown".into());
-    ("OS Age".into(), value)
+/// Only the install time is cached; it changes with a reinstall at most.
+const CACHE_TTL: Duration = Duration::from_secs(24 * 3600);
+
+/// Returns install time (Unix seconds) and age, computed now; both absent if unknown.
+fn item(installed: Option<u64>) -> InfoItem {
+    let age = installed.map(|t| unix_now().saturating_sub(t));
+    InfoItem::new("OS Age")
+        .with_opt("install_time", installed)
+        .with_opt("age_seconds", age)
 }
 
-fn detect_os_age() -> Option<String> {
+fn detect_install_time() -> Option<u64> {
     #[cfg(target_os = "linux")]
     {
         let created = fs_created("/")
             .or_else(|| fs_created("/var/log/installer"))
             .or_else(|| fs_created("/etc"))
             .or_else(|| fs_created("/var/log"));
-        created.and_then(format_duration_since)
+        created.and_then(|t| t.duration_since(UNIX_EPOCH).ok().map(|d| d.as_secs()))
     }
 
     #[cfg(not(target_os = "linux"))]
@@ -28,11 +35,15 @@ fn fs_created(path: &str) -> Option<SystemTime> {
     std::fs::metadata(path).ok().and_then(|m| m.created().ok())
 }
 
-#[cfg(target_os = "linux")]
-fn format_duration_since(system_time: SystemTime) -> Option<String> {
-    let created_secs = system_time.duration_since(UNIX_EPOCH).ok()?.as_secs();
-    let now_secs = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
-    let secs = now_secs.saturating_sub(created_secs);
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// OS age string, e.g. "2 years, 3 months, 4 days" or "Less than a day".
+fn format_age(secs: u64) -> String {
     let days = secs / 86400;
     let years = days / 365;
     let rem = days % 365;
@@ -54,8 +65,68 @@ fn format_duration_since(system_time: SystemTime) -> Option<String> {
     })
     .collect();
     if parts.is_empty() {
-        Some("Less than a day".into())
+        "Less than a day".into()
     } else {
-        Some(parts.join(", "))
+        parts.join(", ")
+    }
+}
+
+const FIELDS: &[FieldSpec] = &[
+    FieldSpec::int("install_time", "Install time (Unix seconds)"),
+    FieldSpec::int("age_seconds", "Seconds since install"),
+];
+
+pub struct OsAge;
+
+impl Module for OsAge {
+    fn key(&self) -> &'static str {
+        "os_age"
+    }
+
+    fn label(&self) -> &'static str {
+        "OS Age"
+    }
+
+    fn category(&self) -> Option<Category> {
+        Some(Category::Software)
+    }
+
+    fn icon(&self) -> &'static str {
+        "\u{f073}" // fa-calendar
+    }
+
+    fn unicode_icon(&self) -> &'static str {
+        "✦"
+    }
+
+    fn emoji_icon(&self) -> &'static str {
+        "🎂"
+    }
+
+    fn fields(&self) -> &'static [FieldSpec] {
+        FIELDS
+    }
+
+    /// The install time is cached; `age_seconds` is always computed live.
+    fn fetch(&self, ctx: &FetchContext) -> Vec<InfoItem> {
+        let installed =
+            ctx.cached_some(self.key(), CachePolicy::new(CACHE_TTL), detect_install_time);
+        vec![item(installed)]
+    }
+
+    fn format(&self, item: &InfoItem, _ctx: &FormatContext) -> String {
+        match item.int("install_time") {
+            Some(t) => format_age(unix_now().saturating_sub(t)),
+            None => "Unknown".into(),
+        }
+    }
+
+    /// Adds `{age}` ("2 years, 3 months, 4 days").
+    fn template_values(&self, item: &InfoItem, _ctx: &FormatContext) -> Vec<(String, String)> {
+        let mut values = field_values(item);
+        if let Some(t) = item.int("install_time") {
+            values.push(("age".into(), format_age(unix_now().saturating_sub(t))));
+        }
+        values
     }
 }
@@ -0,0 +1,131 @@
+//! `/etc/os-release` (falling back to `/usr/lib/os-release`): distro ID, parents, names and
+//! the branding hints used for logo detection.
+
+use std::sync::OnceLock;
+
+use crate::ui::color;
+
+/// Fields of os-release that NovaFetch uses. Missing keys are None / empty.
+#[derive(Debug, Clone, Default)]
+pub struct OsRelease {
+    /// `ID`, e.g. "arch", "ubuntu".
+    pub id: Option<String>,
+    /// `ID_LIKE`: parent distros, closest first (e.g. ["ubuntu", "debian"]).
+    pub id_like: Vec<String>,
+    pub name: Option<String>,
+    pub pretty_name: Option<String>,
+    pub version_id: Option<String>,
+    pub version_codename: Option<String>,
+    /// `ANSI_COLOR`: SGR parameters of the distro's color, e.g. "38;2;23;147;209" or "0;36".
+    pub ansi_color: Option<String>,
+    /// `LOGO`: icon name of the distro logo, e.g. "archlinux-logo".
+    pub logo: Option<String>,
+}
+
+static OS_RELEASE: OnceLock<Option<OsRelease>> = OnceLock::new();
+
+impl OsRelease {
+    /// os-release of the running system, read once. None if neither file exists.
+    pub fn get() -> Option<&'static OsRelease> {
+        OS_RELEASE
+            .get_or_init(|| {
+                ["/etc/os-release", "/usr/lib/os-release"]
+                    .iter()
+                    .find_map(|p| std::fs::read_to_string(p).ok())
+                    .map(|s| OsRelease::parse(&s))
+            })
+            .as_ref()
+    }
+
+    /// Parses `KEY=value` lines; values may be quoted with `"` or `'` (shell-style escapes in `"`).
+    pub fn parse(content: &str) -> Self {
+        let mut out = OsRelease::default();
+        for line in content.lines() {
+            let line = line.trim();
+            if line.starts_with('#') {
+                continue;
+            }
+            let Some((key, raw)) = line.split_once('=') else {
+                continue;
+            };
+            let value = unquote(raw.trim());
+            if value.is_empty() {
+                continue;
+            }
+            match key.trim() {
+                "ID" => out.id = Some(value.to_lowercase()),
+                "ID_LIKE" => {
+                    out.id_like = value.split_whitespace().map(str::to_lowercase).collect()
+                }
+                "NAME" => out.name = Some(value),
+                "PRETTY_NAME" => out.pretty_name = Some(value),
+                "VERSION_ID" => out.version_id = Some(value),
+                "VERSION_CODENAME" => out.version_codename = Some(value),
+                "ANSI_COLOR" => out.ansi_color = Some(value),
+                "LOGO" => out.logo = Some(value),
+                _ => {}
+            }
+        }
+        out
+    }
+
+    /// `ANSI_COLOR` as RGB: TrueColor (`38;2;r;g;b`), 256-color (`38;5;n`) or one of the
+    /// 16 standard colors (30–37, 90–97). Other attributes such as bold are ignored.
+    pub fn ansi_rgb(&self) -> Option<[u8; 3]> {
+        color::parse_sgr_color(self.ansi_color.as_deref()?)
+    }
+}
+
+fn unquote(raw: &str) -> String {
+    let mut chars = raw.chars();
+    match chars.next() {
+        Some(q @ ('"' | '\'')) => {
+            let mut out = String::new();
+            while let Some(c) = chars.next() {
+                match c {
+                    c if c == q => break,
+                    '\\' if q == '"' => out.extend(chars.next()),
+                    c => out.push(c),
+                }
+            }
+            out
+        }
+        _ => raw.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_quoted_values() {
+        let os = OsRelease::parse(
+            "# comment\nNAME=\"Pop!_OS\"\nID=Pop\nID_LIKE=\"ubuntu debian\"\n\
+             PRETTY_NAME='Pop!_OS 22.04 LTS'\nVERSION_ID=\"22.04\"\nVERSION_CODENAME=\n\
+             LOGO=distributor-logo-pop-os\nANSI_COLOR=\"0;36\"\nHOME_URL=\"https://pop.system76.com\"\n",
+        );
+        assert_eq!(os.id.as_deref(), Some("pop"));
+        assert_eq!(os.id_like, ["ubuntu", "debian"]);
+        assert_eq!(os.name.as_deref(), Some("Pop!_OS"));
+        assert_eq!(os.pretty_name.as_deref(), Some("Pop!_OS 22.04 LTS"));
+        assert_eq!(os.version_id.as_deref(), Some("22.04"));
+        assert_eq!(os.version_codename, None);
+        assert_eq!(os.logo.as_deref(), Some("distributor-logo-pop-os"));
+        assert_eq!(os.ansi_color.as_deref(), Some("0;36"));
+    }
+
+    #[test]
+    fn unquote_handles_escapes() {
+        assert_eq!(unquote(r#""a \"b\" \\c""#), r#"a "b" \c"#);
+        assert_eq!(unquote(r"'a \b'"), r"a \b");
+        assert_eq!(unquote("plain"), "plain");
+    }
+
+    #[test]
+    fn ansi_color_as_rgb() {
+        let truecolor = OsRelease::parse("ANSI_COLOR=\"38;2;23;147;209\"");
+        assert_eq!(truecolor.ansi_rgb(), Some([23, 147, 209]));
+        assert_eq!(OsRelease::default().ansi_rgb(), None);
+    }
+}
@@ -4,33 +4,40 @@
 use std::fs;
 use std::path::Path;
 use std::process::Command;
+use std::time::Duration;
 
-/// Returns package count string, e.g. "1234 (pacman), 12 (flatpak)".
-pub fn get() -> (String, String) {
-    let mut parts = Vec::new();
+use super::{Category, FetchContext, FieldSpec, FormatContext, InfoItem, Module};
+use crate::cache::CachePolicy;
 
-    if let Some(n) = count_pacman() {
-        parts.push(format!("{} (pacman)", n));
-    }
-    if let Some(n) = count_dpkg() {
-        parts.push(format!("{} (dpkg)", n));
-    }
-    if let Some(n) = count_rpm() {
-        parts.push(format!("{} (rpm)", n));
-    }
-    if let Some(n) = count_flatpak() {
-        parts.push(format!("{} (flatpak)", n));
-    }
-    if let Some(n) = count_snap() {
-        parts.push(format!("{} (snap)", n));
-    }
+/// Package databases touched by installs/removals; any mtime change invalidates the cached count.
+const CACHE_HINTS: &[&str] = &[
+    "/var/lib/pacman/local",
+    "/var/lib/dpkg/status",
+    "/var/lib/rpm",
+    "/var/lib/flatpak/app",
+    "/var/lib/snapd/state.json",
+];
+
+const CACHE_TTL: Duration = Duration::from_secs(24 * 3600);
 
-    let value = if parts.is_empty() {
-        "—".into()
-    } else {
-        parts.join(", ")
-    };
-    ("Packages".into(), value)
+/// Returns one count field per detected package manager (pacman, dpkg, rpm, flatpak, snap) plus `total`.
+pub fn get() -> InfoItem {
+    let counts = [
+        ("pacman", count_pacman()),
+        ("dpkg", count_dpkg()),
+        ("rpm", count_rpm()),
+        ("flatpak", count_flatpak()),
+        ("snap", count_snap()),
+    ];
+    let mut item = InfoItem::new("Packages");
+    let mut total = 0u32;
+    for (manager, count) in counts {
+        if let Some(n) = count {
+            item = item.with(manager, n);
+            total += n;
+        }
+    }
+    item.with("total", total)
 }
 
 #[cfg(target_os = "linux")]
@@ -92,3 +99,66 @@ fn count_lines_skip_header(cmd: &mut Command) -> Option<u32> {
     let n = lines.count() as u32;
     Some(n)
 }
+
+const FIELDS: &[FieldSpec] = &[
+    FieldSpec::int("pacman", "Installed pacman packages"),
+    FieldSpec::int("dpkg", "Installed dpkg packages"),
+    FieldSpec::int("rpm", "Installed rpm packages"),
+    FieldSpec::int("flatpak", "Installed flatpaks"),
+    FieldSpec::int("snap", "Installed snaps"),
+    FieldSpec::int("total", "Sum over all package managers"),
+];
+
+pub struct Packages;
+
+impl Module for Packages {
+    fn key(&self) -> &'static str {
+        "packages"
+    }
+
+    fn label(&self) -> &'static str {
+        "Packages"
+    }
+
+    fn category(&self) -> Option<Category> {
+        Some(Category::Software)
+    }
+
+    fn icon(&self) -> &'static str {
+        "\u{f187}" // fa-cube
+    }
+
+    fn unicode_icon(&self) -> &'static str {
+        "□"
+    }
+
+    fn emoji_icon(&self) -> &'static str {
+        "📦"
+    }
+
+    fn fields(&self) -> &'static [FieldSpec] {
+        FIELDS
+    }
+
+    fn fetch(&self, ctx: &FetchContext) -> Vec<InfoItem> {
+        let policy = CACHE_HINTS
+            .iter()
+            .fold(CachePolicy::new(CACHE_TTL), |p, h| p.with_hint(h));
+        vec![ctx.cached(self.key(), policy, get)]
+    }
+
+    fn format(&self, item: &InfoItem, _ctx: &FormatContext) -> String {
+        // e.g. "1234 (pacman), 12 (flatpak)"
+        let parts: Vec<String> = item
+            .fields
+            .iter()
+            .filter(|(name, _)| name != "total")
+            .filter_map(|(name, _)| item.int(name).map(|n| format!("{} ({})", n, name)))
+            .collect();
+        if parts.is_empty() {
+            "—".into()
+        } else {
+            parts.join(", ")
+        }
+    }
+}
@@ -1,31 +1,68 @@
 //! ANSI color palette row (neofetch-style blocks at bottom of fetch).
 
+use super::{Category, FetchContext, FieldSpec, FormatContext, InfoItem, Module};
+
 /// Reset ANSI code.
 const RESET: &str = "\x1b[0m";
 
-/// Builds two strings (normal + bright colors) for the 8 standard (30–37) and 8 bright (90–97) ANSI colors.
+/// First SGR code of each palette row: 8 standard (30–37) and 8 bright (90–97) ANSI colors.
+const ROW_STARTS: [u64; 2] = [30, 90];
+
+/// Builds one row of 8 blocks starting at SGR code `first` (30 = normal, 90 = bright).
 /// Each block: `\x1b[{i}m██ \x1b[0m`. When `no_color` is true, returns plain blocks (no ANSI).
-/// Returns Vec with two elements: [normal_colors, bright_colors].
-pub fn get_color_palette(no_color: bool) -> Vec<String> {
+pub fn color_palette_row(first: u64, no_color: bool) -> String {
     if no_color {
-        return plain_blocks();
-    }
-    
-    let mut normal = String::new();
-    for i in 30..=37 {
-        normal.push_str(&format!("\x1b[{}m██ {}", i, RESET));
+        let row: String = (0..8).map(|_| "██ ").collect();
+        return row.trim_end().to_string();
     }
-    
-    let mut bright = String::new();
-    for i in 90..=97 {
-        bright.push_str(&format!("\x1b[{}m██ {}", i, RESET));
+
+    let mut row = String::new();
+    for i in first..first + 8 {
+        row.push_str(&format!("\x1b[{}m██ {}", i, RESET));
     }
-    
-    vec![normal, bright]
+    row
 }
 
-fn plain_blocks() -> Vec<String> {
-    let block = "██ ";
-    let row: String = (0..8).map(|_| block).collect();
-    vec![row.trim_end().to_string(), row.trim_end().to_string()]
+const FIELDS: &[FieldSpec] = &[
+    // Public: machine outputs show each row as its first code
+    FieldSpec::int(
+        "first_code",
+        "SGR foreground code of the row's first color: 30 (normal, 30-37) or 90 (bright, 90-97)",
+    ),
+];
+
+pub struct Palette;
+
+impl Module for Palette {
+    fn key(&self) -> &'static str {
+        "palette"
+    }
+
+    fn label(&self) -> &'static str {
+        "Palette"
+    }
+
+    fn category(&self) -> Option<Category> {
+        None
+    }
+
+    fn fields(&self) -> &'static [FieldSpec] {
+        FIELDS
+    }
+
+    fn multi_item(&self) -> bool {
+        true
+    }
+
+    fn fetch(&self, _ctx: &FetchContext) -> Vec<InfoItem> {
+        // Two lines (normal + bright colors), printed without label at the very end
+        ROW_STARTS
+            .iter()
+            .map(|&first| InfoItem::new("").with("first_code", first))
+            .collect()
+    }
+
+    fn format(&self, item: &InfoItem, ctx: &FormatContext) -> String {
+        color_palette_row(item.int("first_code").unwrap_or(30), ctx.no_color)
+    }
 }
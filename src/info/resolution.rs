@@ -2,22 +2,89 @@
 
 use display_info::DisplayInfo;
 
-/// Returns resolution string, e.g. "1920x1080 @ 144Hz" or "1920x1080 @ 60Hz, 2560x1440 @ 60Hz".
-pub fn get() -> (String, String) {
-    let value = match DisplayInfo::all() {
-        Ok(displays) if !displays.is_empty() => displays
-            .iter()
-            .map(|d| {
-                let hz = if d.frequency > 0.0 {
-                    format!(" @ {}Hz", d.frequency as u32)
-                } else {
-                    String::new()
-                };
-                format!("{}x{}{}", d.width, d.height, hz)
-            })
-            .collect::<Vec<_>>()
-            .join(", "),
-        _ => "—".into(),
+use super::{Category, FetchContext, FieldSpec, FormatContext, InfoItem, Module};
+
+/// Returns one item per display (width, height, refresh_hz, name).
+/// With several displays the label names the output, e.g. "Resolution (HDMI-1)".
+/// No display detected = one item without fields, shown as "—".
+pub fn get() -> Vec<InfoItem> {
+    let displays = match DisplayInfo::all() {
+        Ok(displays) if !displays.is_empty() => displays,
+        _ => return vec![InfoItem::new("Resolution")],
     };
-    ("Resolution".into(), value)
+    let multiple = displays.len() > 1;
+    displays
+        .iter()
+        .map(|d| {
+            let label = if multiple && !d.name.is_empty() {
+                format!("Resolution ({})", d.name)
+            } else {
+                "Resolution".into()
+            };
+            let hz = (d.frequency > 0.0).then_some(d.frequency as u32);
+            InfoItem::new(label)
+                .with("width", d.width)
+                .with("height", d.height)
+                .with_opt("refresh_hz", hz)
+                .with_opt("name", (!d.name.is_empty()).then(|| d.name.clone()))
+        })
+        .collect()
+}
+
+const FIELDS: &[FieldSpec] = &[
+    FieldSpec::int("width", "Width in pixels"),
+    FieldSpec::int("height", "Height in pixels"),
+    FieldSpec::int("refresh_hz", "Refresh rate in Hz"),
+    FieldSpec::text("name", "Output name, e.g. HDMI-1"),
+];
+
+pub struct Resolution;
+
+impl Module for Resolution {
+    fn key(&self) -> &'static str {
+        "resolution"
+    }
+
+    fn label(&self) -> &'static str {
+        "Resolution"
+    }
+
+    fn category(&self) -> Option<Category> {
+        Some(Category::Hardware)
+    }
+
+    fn icon(&self) -> &'static str {
+        "\u{f108}" // fa-desktop
+    }
+
+    fn unicode_icon(&self) -> &'static str {
+        "▭"
+    }
+
+    fn emoji_icon(&self) -> &'static str {
+        "📺"
+    }
+
+    fn fields(&self) -> &'static [FieldSpec] {
+        FIELDS
+    }
+
+    fn multi_item(&self) -> bool {
+        true
+    }
+
+    fn fetch(&self, _ctx: &FetchContext) -> Vec<InfoItem> {
+        get()
+    }
+
+    fn format(&self, item: &InfoItem, _ctx: &FormatContext) -> String {
+        // e.g. "1920x1080 @ 144Hz"
+        match (item.int("width"), item.int("height")) {
+            (Some(w), Some(h)) => match item.int("refresh_hz") {
+                Some(hz) => format!("{}x{} @ {}Hz", w, h, hz),
+                None => format!("{}x{}", w, h),
+            },
+            _ => "—".into(),
+        }
+    }
 }
@@ -1,19 +1,24 @@
 use std::env;
 use std::process::Command;
+use std::time::Duration;
 
-pub fn get() -> (String, String) {
+use super::{Category, FetchContext, FieldSpec, FormatContext, InfoItem, Module};
+use crate::cache::CachePolicy;
+
+/// `$SHELL --version` only changes on upgrade, which the `$SHELL` mtime hint catches.
+const CACHE_TTL: Duration = Duration::from_secs(7 * 24 * 3600);
+
+pub fn get() -> InfoItem {
     let shell_path = env::var("SHELL").unwrap_or_else(|_| "unknown".into());
     let name = shell_path
         .rsplit('/')
         .next()
         .unwrap_or(shell_path.as_str());
     let version = get_shell_version(&shell_path);
-    let shell = if version.is_empty() {
-        name.to_string()
-    } else {
-        format!("{} {}", name, version)
-    };
-    ("Shell".into(), shell)
+    InfoItem::new("Shell")
+        .with("name", name)
+        .with_opt("version", (!version.is_empty()).then_some(version))
+        .with("path", shell_path.as_str())
 }
 
 fn get_shell_version(shell_path: &str) -> String {
@@ -36,3 +41,57 @@ fn get_shell_version(shell_path: &str) -> String {
         .filter(|v| !v.is_empty())
         .unwrap_or_default()
 }
+
+const FIELDS: &[FieldSpec] = &[
+    FieldSpec::text("name", "Shell binary name"),
+    FieldSpec::text("version", "Shell version"),
+    FieldSpec::text("path", "Value of $SHELL"),
+];
+
+pub struct Shell;
+
+impl Module for Shell {
+    fn key(&self) -> &'static str {
+        "shell"
+    }
+
+    fn label(&self) -> &'static str {
+        "Shell"
+    }
+
+    fn category(&self) -> Option<Category> {
+        Some(Category::Software)
+    }
+
+    fn icon(&self) -> &'static str {
+        "\u{f489}" // fa-terminal (alt)
+    }
+
+    fn unicode_icon(&self) -> &'static str {
+        "$"
+    }
+
+    fn emoji_icon(&self) -> &'static str {
+        "🐚"
+    }
+
+    fn fields(&self) -> &'static [FieldSpec] {
+        FIELDS
+    }
+
+    fn fetch(&self, ctx: &FetchContext) -> Vec<InfoItem> {
+        let mut policy = CachePolicy::new(CACHE_TTL);
+        if let Some(path) = env::var_os("SHELL") {
+            policy = policy.with_hint(path);
+        }
+        vec![ctx.cached(self.key(), policy, get)]
+    }
+
+    fn format(&self, item: &InfoItem, _ctx: &FormatContext) -> String {
+        let name = item.text("name").unwrap_or("unknown");
+        match item.text("version") {
+            Some(version) => format!("{} {}", name, version),
+            None => name.to_string(),
+        }
+    }
+}
@@ -1,20 +1,82 @@
 //! Swap memory (used/total and percentage) via sysinfo.
 
-use sysinfo::System;
+use sysinfo::{MemoryRefreshKind, RefreshKind, System};
 
 use crate::info::utils;
 
-/// Returns swap line: "Used / Total (Percentage%)" with unit_type formatting.
-pub fn get(sys: &System, unit_type: &str) -> (String, String) {
-    let total = sys.total_swap();
-    let used = sys.used_swap();
-    let used_str = utils::format_bytes(used, unit_type);
-    let total_str = utils::format_bytes(total, unit_type);
-    let pct = if total > 0 {
-        (used as f64 / total as f64 * 100.0).round() as u32
-    } else {
-        0
-    };
-    let value = format!("{} / {} ({}%)", used_str, total_str, pct);
-    ("Swap".into(), value)
+use super::{
+    field_values, usage_values, Category, FetchContext, FieldSpec, FormatContext, InfoItem, Module,
+};
+
+/// Returns used/total swap in bytes.
+pub fn get(sys: &System) -> InfoItem {
+    InfoItem::new("Swap")
+        .with("used_bytes", sys.used_swap())
+        .with("total_bytes", sys.total_swap())
+}
+
+const FIELDS: &[FieldSpec] = &[
+    FieldSpec::int("used_bytes", "Used swap in bytes"),
+    FieldSpec::int("total_bytes", "Total swap in bytes"),
+];
+
+pub struct Swap;
+
+impl Module for Swap {
+    fn key(&self) -> &'static str {
+        "swap"
+    }
+
+    fn label(&self) -> &'static str {
+        "Swap"
+    }
+
+    fn category(&self) -> Option<Category> {
+        Some(Category::Hardware)
+    }
+
+    fn icon(&self) -> &'static str {
+        "\u{f2db}" // fa-exchange
+    }
+
+    fn unicode_icon(&self) -> &'static str {
+        "⇄"
+    }
+
+    fn emoji_icon(&self) -> &'static str {
+        "🔁"
+    }
+
+    fn fields(&self) -> &'static [FieldSpec] {
+        FIELDS
+    }
+
+    fn refresh_kind(&self) -> RefreshKind {
+        RefreshKind::nothing().with_memory(MemoryRefreshKind::nothing().with_swap())
+    }
+
+    fn fetch(&self, ctx: &FetchContext) -> Vec<InfoItem> {
+        ctx.sys.map(|s| vec![get(s)]).unwrap_or_default()
+    }
+
+    fn format(&self, item: &InfoItem, ctx: &FormatContext) -> String {
+        // "Used / Total (Percentage%)" with unit_type formatting
+        let unit_type = &ctx.config.general.unit_type;
+        let used = item.int("used_bytes").unwrap_or(0);
+        let total = item.int("total_bytes").unwrap_or(0);
+        let pct = utils::percent(used, total);
+        format!(
+            "{} / {} ({}%)",
+            utils::format_bytes(used, unit_type),
+            utils::format_bytes(total, unit_type),
+            pct
+        )
+    }
+
+    /// Adds `{used}`, `{total}`, `{percent}` and `{bar}`.
+    fn template_values(&self, item: &InfoItem, ctx: &FormatContext) -> Vec<(String, String)> {
+        let mut values = field_values(item);
+        values.extend(usage_values(item, &ctx.config.general.unit_type));
+        values
+    }
 }
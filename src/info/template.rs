@@ -0,0 +1,103 @@
+//! Per-module format templates (`[modules.<key>] format = "..."`).
+//!
+//! - `{name}` inserts a named value (see `Module::template_values`); unknown or empty = missing.
+//! - `[...]` is an optional section: it disappears when any `{name}` directly inside it is
+//!   missing. Sections nest; a missing value in a nested section only hides that section.
+//! - `\` escapes the next character (`\{`, `\[`, `\]`, `\\`).
+//!
+//! Example: `"{model}[ ({cores}c)] {usage}%[ {temp}]"`.
+
+use super::{bar, utils, Field, InfoItem};
+
+/// Renders `template` with `values`. Missing values outside any section render as nothing.
+pub fn render_template(template: &str, values: &[(String, String)]) -> String {
+    let chars: Vec<char> = template.chars().collect();
+    let mut pos = 0;
+    render_seq(&chars, &mut pos, values, false).0
+}
+
+/// Renders until the end of input, or the closing `]` when `in_section`.
+/// Returns the text and whether every value referenced at this level was present.
+fn render_seq(
+    chars: &[char],
+    pos: &mut usize,
+    values: &[(String, String)],
+    in_section: bool,
+) -> (String, bool) {
+    let mut out = String::new();
+    let mut complete = true;
+    while *pos < chars.len() {
+        match chars[*pos] {
+            '\\' if *pos + 1 < chars.len() => {
+                out.push(chars[*pos + 1]);
+                *pos += 2;
+            }
+            '{' => match chars[*pos + 1..].iter().position(|&c| c == '}') {
+                Some(len) => {
+                    let name: String = chars[*pos + 1..*pos + 1 + len].iter().collect();
+                    *pos += len + 2;
+                    match lookup(values, name.trim()) {
+                        Some(v) => out.push_str(v),
+                        None => complete = false,
+                    }
+                }
+                // Unclosed brace: keep it literally
+                None => {
+                    out.push('{');
+                    *pos += 1;
+                }
+            },
+            '[' => {
+                *pos += 1;
+                let (section, section_complete) = render_seq(chars, pos, values, true);
+                if section_complete {
+                    out.push_str(&section);
+                }
+            }
+            ']' if in_section => {
+                *pos += 1;
+                return (out, complete);
+            }
+            c => {
+                out.push(c);
+                *pos += 1;
+            }
+        }
+    }
+    (out, complete)
+}
+
+fn lookup<'a>(values: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    values
+        .iter()
+        .find(|(n, _)| n == name)
+        .map(|(_, v)| v.as_str())
+        .filter(|v| !v.is_empty())
+}
+
+/// The item's raw fields as template values (`{used_bytes}`, `{temperature_c}`, ...).
+pub fn field_values(item: &InfoItem) -> Vec<(String, String)> {
+    item.fields
+        .iter()
+        .map(|(name, field)| {
+            let value = match field {
+                Field::Int(n) => n.to_string(),
+                Field::Float(f) => f.to_string(),
+                Field::Text(s) => s.clone(),
+            };
+            (name.clone(), value)
+        })
+        .collect()
+}
+
+/// `{used}`, `{total}` (per `unit_type`), `{percent}` and `{bar}` from `used_bytes`/`total_bytes`.
+pub fn usage_values(item: &InfoItem, unit_type: &str) -> Vec<(String, String)> {
+    let used = item.int("used_bytes").unwrap_or(0);
+    let total = item.int("total_bytes").unwrap_or(0);
+    vec![
+        ("used".into(), utils::format_bytes(used, unit_type)),
+        ("total".into(), utils::format_bytes(total, unit_type)),
+        ("percent".into(), utils::percent(used, total).to_string()),
+        ("bar".into(), bar::bar(used, total, 10)),
+    ]
+}
@@ -4,9 +4,11 @@ use std::env;
 
 use sysinfo::{Pid, ProcessRefreshKind, RefreshKind, System};
 
-pub fn get() -> (String, String) {
-    let name = detect_via_env().or_else(detect_via_parent_process).unwrap_or_else(|| "—".into());
-    ("Terminal".into(), name)
+use super::{Category, FetchContext, FieldSpec, FormatContext, InfoItem, Module};
+
+pub fn get() -> InfoItem {
+    let name = detect_via_env().or_else(detect_via_parent_process);
+    InfoItem::new("Terminal").with_opt("name", name)
 }
 
 fn detect_via_env() -> Option<String> {
@@ -76,3 +78,47 @@ fn clean_terminal_name(name: &str) -> String {
     }
     s.to_string()
 }
+
+const FIELDS: &[FieldSpec] = &[
+    FieldSpec::text("name", "Terminal emulator"),
+];
+
+pub struct Terminal;
+
+impl Module for Terminal {
+    fn key(&self) -> &'static str {
+        "terminal"
+    }
+
+    fn label(&self) -> &'static str {
+        "Terminal"
+    }
+
+    fn category(&self) -> Option<Category> {
+        Some(Category::Software)
+    }
+
+    fn icon(&self) -> &'static str {
+        "\u{f120}" // fa-terminal
+    }
+
+    fn unicode_icon(&self) -> &'static str {
+        "▶"
+    }
+
+    fn emoji_icon(&self) -> &'static str {
+        "📟"
+    }
+
+    fn fields(&self) -> &'static [FieldSpec] {
+        FIELDS
+    }
+
+    fn fetch(&self, _ctx: &FetchContext) -> Vec<InfoItem> {
+        vec![get()]
+    }
+
+    fn format(&self, item: &InfoItem, _ctx: &FormatContext) -> String {
+        item.text("name").unwrap_or("—").to_string()
+    }
+}
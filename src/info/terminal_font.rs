@@ -1,10 +1,16 @@
 //! Terminal font: gsettings (GNOME/GTK monospace) or fallback.
 
 use std::process::Command;
+use std::time::Duration;
 
-pub fn get() -> (String, String) {
-    let value = get_gsettings_monospace().unwrap_or_else(|| "Unknown (Terminal-specific)".into());
-    ("Terminal Font".into(), value)
+use super::{Category, FetchContext, FieldSpec, FormatContext, InfoItem, Module};
+use crate::cache::CachePolicy;
+
+/// gsettings values live in the dconf database; its mtime hint catches changes early.
+const CACHE_TTL: Duration = Duration::from_secs(24 * 3600);
+
+pub fn get() -> InfoItem {
+    InfoItem::new("Terminal Font").with_opt("font", get_gsettings_monospace())
 }
 
 fn get_gsettings_monospace() -> Option<String> {
@@ -22,3 +28,53 @@ fn get_gsettings_monospace() -> Option<String> {
     }
     Some(s.to_string())
 }
+
+const FIELDS: &[FieldSpec] = &[
+    FieldSpec::text("font", "Monospace font name"),
+];
+
+pub struct TerminalFont;
+
+impl Module for TerminalFont {
+    fn key(&self) -> &'static str {
+        "terminal_font"
+    }
+
+    fn label(&self) -> &'static str {
+        "Terminal Font"
+    }
+
+    fn category(&self) -> Option<Category> {
+        Some(Category::Software)
+    }
+
+    fn icon(&self) -> &'static str {
+        "\u{f031}" // fa-font
+    }
+
+    fn unicode_icon(&self) -> &'static str {
+        "¶"
+    }
+
+    fn emoji_icon(&self) -> &'static str {
+        "🔤"
+    }
+
+    fn fields(&self) -> &'static [FieldSpec] {
+        FIELDS
+    }
+
+    fn fetch(&self, ctx: &FetchContext) -> Vec<InfoItem> {
+        let mut policy = CachePolicy::new(CACHE_TTL);
+        if let Some(dir) = dirs::config_dir() {
+            policy = policy.with_hint(dir.join("dconf").join("user"));
+        }
+        vec![ctx.cached(self.key(), policy, get)]
+    }
+
+    fn format(&self, item: &InfoItem, _ctx: &FormatContext) -> String {
+        item.text("font")
+            .unwrap_or("Unknown (Terminal-specific)")
+            .to_string()
+    }
+}
@@ -5,6 +5,8 @@ use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
 
+use super::{Category, FetchContext, FieldSpec, FormatContext, InfoItem, Module};
+
 /// Keys we look for (case-sensitive in the file).
 const GTK_THEME_KEY: &str = "gtk-theme-name";
 const GTK_ICON_THEME_KEY: &str = "gtk-icon-theme-name";
@@ -60,8 +62,9 @@ fn parse_settings_file(
 /// Extracts GTK Theme, Icon Theme, and Font.
 /// Tries `$HOME/.config/gtk-3.0/settings.ini` first, then `gtk-4.0/settings.ini`.
 /// If both fail, uses `GTK_THEME` env var for theme name if set.
-/// Values are trimmed and quotes stripped. Missing file/keys yield "Unknown".
-pub fn get_theme_info() -> Vec<(String, String)> {
+/// Values are trimmed and quotes stripped. Missing file/keys leave the field absent ("Unknown").
+/// One item per line (Theme, Icons, Font), each with a single field.
+pub fn get_theme_info() -> Vec<InfoItem> {
     let mut theme = String::from("Unknown");
     let mut icons = String::from("Unknown");
     let mut font = String::from("Unknown");
@@ -91,9 +94,60 @@ pub fn get_theme_info() -> Vec<(String, String)> {
         }
     }
 
-    vec![
-        ("Theme".into(), theme),
-        ("Icons".into(), icons),
-        ("Font".into(), font),
+    [
+        ("Theme", "gtk_theme", theme),
+        ("Icons", "icon_theme", icons),
+        ("Font", "font", font),
     ]
+    .into_iter()
+    .map(|(label, field, value)| {
+        InfoItem::new(label).with_opt(field, (value != "Unknown").then_some(value))
+    })
+    .collect()
+}
+
+const FIELDS: &[FieldSpec] = &[
+    FieldSpec::text("gtk_theme", "GTK theme name"),
+    FieldSpec::text("icon_theme", "GTK icon theme name"),
+    FieldSpec::text("font", "GTK font name"),
+];
+
+pub struct Theme;
+
+impl Module for Theme {
+    fn key(&self) -> &'static str {
+        "theme"
+    }
+
+    fn label(&self) -> &'static str {
+        "Theme"
+    }
+
+    fn category(&self) -> Option<Category> {
+        Some(Category::Software)
+    }
+
+    fn unicode_icon(&self) -> &'static str {
+        "✎"
+    }
+
+    fn emoji_icon(&self) -> &'static str {
+        "🎨"
+    }
+
+    fn fields(&self) -> &'static [FieldSpec] {
+        FIELDS
+    }
+
+    fn fetch(&self, _ctx: &FetchContext) -> Vec<InfoItem> {
+        get_theme_info()
+    }
+
+    fn format(&self, item: &InfoItem, _ctx: &FormatContext) -> String {
+        item.fields
+            .first()
+            .and_then(|(name, _)| item.text(name))
+            .unwrap_or("Unknown")
+            .to_string()
+    }
 }
@@ -1,7 +1,13 @@
 use sysinfo::System;
 
-pub fn get() -> (String, String) {
-    let secs = System::uptime();
+use super::{Category, FetchContext, FieldSpec, FormatContext, InfoItem, Module};
+
+pub fn get() -> InfoItem {
+    InfoItem::new("Uptime").with("seconds", System::uptime())
+}
+
+/// "2 days, 3 hours, 4 mins"; minutes are always shown when nothing else is.
+fn format_uptime(secs: u64) -> String {
     let days = secs / 86400;
     let hours = (secs % 86400) / 3600;
     let mins = (secs % 3600) / 60;
@@ -15,6 +21,48 @@ pub fn get() -> (String, String) {
     if mins > 0 || parts.is_empty() {
         parts.push(format!("{} min{}", mins, if mins == 1 { "" } else { "s" }));
     }
-    let uptime = parts.join(", ");
-    ("Uptime".into(), uptime)
+    parts.join(", ")
+}
+
+const FIELDS: &[FieldSpec] = &[
+    FieldSpec::int("seconds", "Seconds since boot"),
+];
+
+pub struct Uptime;
+
+impl Module for Uptime {
+    fn key(&self) -> &'static str {
+        "uptime"
+    }
+
+    fn label(&self) -> &'static str {
+        "Uptime"
+    }
+
+    fn category(&self) -> Option<Category> {
+        Some(Category::Status)
+    }
+
+    fn icon(&self) -> &'static str {
+        "\u{f017}" // fa-clock-o
+    }
+
+    fn unicode_icon(&self) -> &'static str {
+        "◷"
+    }
+
+    fn emoji_icon(&self) -> &'static str {
+        "⏰"
+    }
+
+    fn fields(&self) -> &'static [FieldSpec] {
+        FIELDS
+    }
+
+    fn fetch(&self, _ctx: &FetchContext) -> Vec<InfoItem> {
+        vec![get()]
+    }
+    fn format(&self, item: &InfoItem, _ctx: &FormatContext) -> String {
+        format_uptime(item.int("seconds").unwrap_or(0))
+    }
 }
@@ -1,5 +1,56 @@
-pub fn get() -> (String, String) {
+use super::{Category, FetchContext, FieldSpec, FormatContext, InfoItem, Module};
+
+pub fn get() -> InfoItem {
     let hostname = whoami::fallible::hostname().unwrap_or_else(|_| "unknown".into());
-    let user_host = format!("{}@{}", whoami::username(), hostname);
-    (String::new(), user_host)
+    InfoItem::new("")
+        .with("user", whoami::username())
+        .with("host", hostname)
+}
+
+const FIELDS: &[FieldSpec] = &[
+    FieldSpec::text("user", "Login name"),
+    FieldSpec::text("host", "Hostname"),
+];
+
+pub struct UserHost;
+
+impl Module for UserHost {
+    fn key(&self) -> &'static str {
+        "user_host"
+    }
+
+    fn label(&self) -> &'static str {
+        "Host"
+    }
+
+    fn category(&self) -> Option<Category> {
+        Some(Category::Hardware)
+    }
+
+    fn icon(&self) -> &'static str {
+        "\u{f007}" // fa-user
+    }
+
+    fn unicode_icon(&self) -> &'static str {
+        "☺"
+    }
+
+    fn emoji_icon(&self) -> &'static str {
+        "👤"
+    }
+
+    fn fields(&self) -> &'static [FieldSpec] {
+        FIELDS
+    }
+
+    fn fetch(&self, _ctx: &FetchContext) -> Vec<InfoItem> {
+        vec![get()]
+    }
+    fn format(&self, item: &InfoItem, _ctx: &FormatContext) -> String {
+        format!(
+            "{}@{}",
+            item.text("user").unwrap_or("unknown"),
+            item.text("host").unwrap_or("unknown")
+        )
+    }
 }
@@ -1,4 +1,5 @@
-//! Byte-formatting helper: configurable unit type (standard / IEC / SI).
+//! Formatting helpers: bytes with configurable unit type (standard / IEC / SI), percentages,
+//! temperatures.
 
 /// Format bytes according to `unit_type`:
 /// - `"standard"` (default): Base 1024, labels "KB", "MB", "GB" (Windows style).
@@ -25,3 +26,17 @@ pub fn format_bytes(bytes: u64, unit_type: &str) -> String {
     }
     format!("{:.2} {}", b, units[idx])
 }
+
+/// `used` as a rounded percentage of `total` (0 if `total` is 0).
+pub fn percent(used: u64, total: u64) -> u64 {
+    if total > 0 {
+        (used as f64 / total as f64 * 100.0).round() as u64
+    } else {
+        0
+    }
+}
+
+/// Temperature with one decimal, e.g. `"52.0°C"`.
+pub fn format_temp(celsius: f64) -> String {
+    format!("{:.1}°C", celsius)
+}
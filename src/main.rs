@@ -1,22 +1,29 @@
+mod cache;
 mod config;
+mod fetch;
 mod info;
 mod modules;
+mod output;
+mod serve;
 mod ui;
 
-use clap::Parser;
-use std::collections::HashMap;
-use std::path::PathBuf;
-
-use config::AppConfig;
-use info::{
-    cpu, de_wm, disk, distro_slug, get_color_palette, get_gpu_name as info_gpu_name,
-    get_gpu_temperature, gpu as info_gpu, kernel, memory, os, os_age, packages, resolution, shell,
-    swap, system_for_fetch, terminal, terminal_font, theme, uptime, user_host,
-};
-use sysinfo::System;
+use clap::{Parser, Subcommand};
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::Arc;
+
+use cache::{Cache, CacheMode};
+use config::{migrate, AppConfig, Overrides};
+use fetch::{fetch_all, format_lines, Line};
+use output::OutputFormat;
+use ui::icons::IconSet;
 use ui::image_render;
+use ui::image_text::{self, ColorDepth, TextRenderer};
 use ui::logos;
 use ui::theme::ThemeManager;
+use ui::tree::build_info_lines;
+use ui::{LogoLayout, Position};
 
 #[derive(Parser, Debug)]
 #[command(name = "novafetch")]
@@ -31,445 +38,579 @@ struct Args {
     no_color: bool,
 
     /// Path to config file (default: ~/.config/novafetch/config.toml)
-    #[arg(long, value_name = "PATH")]
+    #[arg(long, value_name = "PATH", global = true)]
     config: Option<PathBuf>,
 
+    /// Apply the config's [profiles.NAME] table (default: $NOVAFETCH_PROFILE)
+    #[arg(long, value_name = "NAME", global = true)]
+    profile: Option<String>,
+
+    /// Override a config setting, e.g. --set theme.mode=solid (repeatable)
+    #[arg(long, value_name = "KEY=VALUE", global = true)]
+    set: Vec<String>,
+
+    /// Terminal width in columns (default: detected; output is not cut when not a terminal)
+    #[arg(long, value_name = "COLS")]
+    width: Option<usize>,
+
     /// Output system info as JSON (skips ASCII art and rendering)
     #[arg(long)]
     json: bool,
+
+    /// Machine-readable output format (skips ASCII art and rendering); --json = --format json
+    #[arg(long, value_name = "FORMAT", conflicts_with = "json")]
+    format: Option<OutputFormat>,
+
+    /// Print the JSON Schema of the --json output and exit
+    #[arg(long)]
+    json_schema: bool,
+
+    /// List the built-in logo names and aliases (and user logos) and exit
+    #[arg(long)]
+    list_logos: bool,
+
+    /// Print a logo in its colors and exit (a "_small" suffix shows the small variant)
+    #[arg(long, value_name = "NAME")]
+    preview_logo: Option<String>,
+
+    /// Print all built-in logos (small variants) in a grid and exit
+    #[arg(long)]
+    print_logos: bool,
+
+    /// Do not read or write the module cache (~/.cache/novafetch)
+    #[arg(long, conflicts_with = "refresh_cache")]
+    no_cache: bool,
+
+    /// Ignore cached module results and store fresh ones
+    #[arg(long)]
+    refresh_cache: bool,
+
+    /// Compute and cache these modules, then exit (started by a run whose modules timed out)
+    #[arg(long, value_name = "KEYS", value_delimiter = ',', hide = true)]
+    fill_cache: Vec<String>,
+
+    #[command(subcommand)]
+    command: Option<Command>,
 }
 
-/// Fetch one or more (label, value) lines for a layout key. Returns (key, label, value) for each line.
-fn fetch_module(
-    key: &str,
-    config: &AppConfig,
-    sys: Option<&System>,
-    no_color: bool,
-) -> Vec<(String, String, String)> {
-    let unit = config.general.unit_type.as_str();
-    let mut out = Vec::new();
-
-    match key {
-        "user_host" => {
-            let (_, v) = user_host();
-            out.push((key.to_string(), String::new(), v));
-        }
-        "os" => {
-            let (l, v) = os();
-            out.push((key.to_string(), l, v));
-        }
-        "kernel" => {
-            let (l, v) = kernel();
-            out.push((key.to_string(), l, v));
-        }
-        "uptime" => {
-            let (l, v) = uptime();
-            out.push((key.to_string(), l, v));
-        }
-        "shell" => {
-            let (l, v) = shell();
-            out.push((key.to_string(), l, v));
-        }
-        "de" => {
-            let (l, v) = de_wm();
-            out.push((key.to_string(), l, v));
-        }
-        "cpu" => {
-            if let Some(s) = sys {
-                let (l, v) = cpu(s, config.general.show_cpu_bar);
-                out.push((key.to_string(), l, v));
-            }
-        }
-        "gpu" => {
-            let name = modules::gpu::get_gpu_name()
-                .or_else(info_gpu_name)
-                .unwrap_or_else(|| "Generic GPU".into());
-            let value = match get_gpu_temperature() {
-                Some(t) => format!("{} ({:.1}°C)", name, t),
-                None => name,
-            };
-            let (default_l, _) = info_gpu();
-            out.push((key.to_string(), default_l, value));
-        }
-        "memory" => {
-            if let Some(s) = sys {
-                let (l, v) = memory(s, config.general.show_memory_bar, unit);
-                out.push((key.to_string(), l, v));
-            }
-        }
-        "disk" => {
-            for (l, v) in disk(config.general.show_disk_bar, "Disk", unit) {
-                out.push((key.to_string(), l, v));
-            }
-        }
-        "terminal" => {
-            let (l, v) = terminal();
-            out.push((key.to_string(), l, v));
-        }
-        "terminal_font" => {
-            let (l, v) = terminal_font();
-            out.push((key.to_string(), l, v));
-        }
-        "packages" => {
-            let (l, v) = packages();
-            out.push((key.to_string(), l, v));
-        }
-        "resolution" => {
-            let (l, v) = resolution();
-            out.push((key.to_string(), l, v));
-        }
-        "swap" => {
-            if let Some(s) = sys {
-                let (l, v) = swap(s, unit);
-                out.push((key.to_string(), l, v));
-            }
-        }
-        "os_age" => {
-            let (l, v) = os_age();
-            out.push((key.to_string(), l, v));
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Serve Prometheus metrics over HTTP (GET /metrics); every scrape runs a fresh fetch
+    Serve {
+        /// Address to listen on, e.g. 127.0.0.1:9184
+        #[arg(long, value_name = "ADDR")]
+        listen: String,
+    },
+    /// Work with the config file
+    Config {
+        #[command(subcommand)]
+        action: ConfigCommand,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum ConfigCommand {
+    /// Write the default config (with a comment on every option)
+    Init {
+        /// Replace an existing config file
+        #[arg(long)]
+        force: bool,
+    },
+    /// Print the default config
+    PrintDefault,
+    /// Print the config as used by a run (all files, profile, environment and --set merged)
+    PrintEffective,
+    /// Print the config file path
+    Path,
+    /// Open the config file in $VISUAL / $EDITOR, then check it
+    Edit,
+    /// Check the config file for errors; exits with status 1 if there are any
+    Check,
+    /// Convert a legacy NovaFetch, neofetch or fastfetch config (printed unless --write)
+    Migrate {
+        /// Config to convert
+        file: PathBuf,
+        /// Format of FILE; detected from its name and content by default
+        #[arg(long, value_enum, value_name = "FORMAT")]
+        from: Option<migrate::Source>,
+        /// Write the result to the config path instead of printing it
+        #[arg(long)]
+        write: bool,
+        /// With --write, replace an existing config file
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+fn main() {
+    let args = Args::parse();
+    if args.json_schema {
+        match serde_json::to_string_pretty(&output::json::schema()) {
+            Ok(s) => println!("{}", s),
+            Err(e) => eprintln!("json error: {}", e),
         }
-        "theme" => {
-            for (l, v) in theme() {
-                out.push((key.to_string(), l, v));
-            }
+        return;
+    }
+    if args.list_logos {
+        for line in logos::list_lines() {
+            println!("{}", line);
         }
-        "media" => {
-            if let Some(v) = modules::media::get_media_status() {
-                out.push((key.to_string(), "Media".into(), v));
-            }
+        return;
+    }
+    if args.print_logos {
+        for line in logos::grid_lines(ui::terminal_width(args.width), args.no_color) {
+            println!("{}", line);
         }
-        "local_ip" => {
-            if let Some(v) = modules::net::get_local_ip() {
-                out.push((key.to_string(), "Local IP".into(), v));
+        return;
+    }
+    let overrides = Overrides {
+        profile: args.profile.clone(),
+        set: args.set.clone(),
+    };
+    if let Some(Command::Config { action }) = &args.command {
+        std::process::exit(run_config_command(
+            action,
+            args.config.as_deref(),
+            &overrides,
+        ));
+    }
+    let config = Arc::new(AppConfig::load(args.config.as_deref(), &overrides));
+    if let Some(name) = &args.preview_logo {
+        match logos::preview_lines(&config, name, args.no_color) {
+            Ok(lines) => {
+                for line in lines {
+                    println!("{}", line);
+                }
             }
-        }
-        "palette" => {
-            // Palette returns Vec<String> with two lines (normal + bright colors)
-            for palette_line in get_color_palette(no_color) {
-                out.push((key.to_string(), String::new(), palette_line));
+            Err(e) => {
+                eprintln!("novafetch: {}", e);
+                std::process::exit(1);
             }
         }
-        _ => {}
+        return;
     }
-    out
-}
+    info::register_custom(&config.custom);
 
-/// Collect all lines (key, label, value) following config.layout.
-fn collect_lines(
-    config: &AppConfig,
-    sys: Option<&System>,
-    no_color: bool,
-) -> Vec<(String, String, String)> {
-    let mut lines = Vec::new();
-    for key in &config.layout {
-        let key = key.trim();
-        if key.is_empty() {
-            continue;
-        }
-        for (k, label, value) in fetch_module(key, config, sys, no_color) {
-            lines.push((k, label, value));
-        }
+    let cache_mode = if args.no_cache || !config.cache.enabled {
+        CacheMode::Off
+    } else if args.refresh_cache {
+        CacheMode::Refresh
+    } else {
+        CacheMode::Use
+    };
+    let cache = Cache::new(cache_mode);
+    if !args.fill_cache.is_empty() {
+        fetch::fill_cache(&config, &args.fill_cache, &cache);
+        return;
     }
-    lines
-}
-
-/// Category definitions for tree layout.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Category {
-    Hardware,
-    Software,
-    Status,
-}
 
-impl Category {
-    fn name(&self) -> &str {
-        match self {
-            Category::Hardware => "Hardware",
-            Category::Software => "Software",
-            Category::Status => "Status",
+    if let Some(Command::Serve { listen }) = &args.command {
+        if let Err(e) = serve::run(listen, &config, &cache) {
+            eprintln!("novafetch: cannot serve on {}: {}", listen, e);
+            std::process::exit(1);
         }
+        return;
     }
 
-    fn header(&self, width: usize) -> String {
-        let name = self.name();
-        let total_dashes = width.saturating_sub(name.len());
-        let left_dashes = total_dashes / 2;
-        let right_dashes = total_dashes - left_dashes;
-        format!(
-            "{}{}{}",
-            "─".repeat(left_dashes),
-            name,
-            "─".repeat(right_dashes)
-        )
-    }
-}
+    let lines = fetch_all(&config, &cache);
 
-/// Categorize a key into Hardware, Software, or Status.
-fn categorize_key(key: &str) -> Option<Category> {
-    match key {
-        "user_host" | "cpu" | "gpu" | "memory" | "disk" | "resolution" | "swap" => {
-            Some(Category::Hardware)
-        }
-        "os" | "kernel" | "de" | "shell" | "terminal" | "terminal_font" | "packages"
-        | "theme" | "os_age" => Some(Category::Software),
-        "uptime" | "local_ip" | "media" => Some(Category::Status),
-        "palette" => None, // Palette is special, shown at the very end
-        _ => None,
+    show(&args, config, &cache, &lines);
+    // Output is complete; timed-out modules fill the cache for the next run in the background
+    // Keys another filler is still working on are left to it
+    let mut unfinished = fetch::unfinished_cache_writes();
+    unfinished.retain(|key| !cache.fill_locked(key));
+    if cache_mode != CacheMode::Off && !unfinished.is_empty() {
+        fill_cache_in_background(&args, &unfinished);
     }
 }
 
-/// Format a group of items with tree structure.
-/// First item uses no prefix (root), middle items use ├─, last item uses └─.
-fn format_group(
-    items: &[(String, String)],
-    theme: &ThemeManager,
-    tree_color: &str,
-) -> Vec<String> {
-    let mut result = Vec::new();
-    let count = items.len();
-    if count == 0 {
-        return result;
+/// Starts `novafetch --fill-cache` detached from the terminal (same config, profile and --set),
+/// so modules that missed their deadline are cached without this run waiting for them.
+fn fill_cache_in_background(args: &Args, keys: &[&str]) {
+    let Ok(exe) = std::env::current_exe() else {
+        return;
+    };
+    let mut command = std::process::Command::new(exe);
+    command.arg("--fill-cache").arg(keys.join(","));
+    if let Some(path) = &args.config {
+        command.arg("--config").arg(path);
     }
+    if let Some(profile) = &args.profile {
+        command.arg("--profile").arg(profile);
+    }
+    for set in &args.set {
+        command.arg("--set").arg(set);
+    }
+    if args.refresh_cache {
+        command.arg("--refresh-cache");
+    }
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+    // Best effort: without it the modules are computed again by the next run
+    let _ = command.spawn();
+}
 
-    for (i, (label, value)) in items.iter().enumerate() {
-        let is_first = i == 0;
-        let is_last = i == count - 1;
-
-        let formatted_value = theme.format_value(value);
-
-        if is_first {
-            // First item: no tree prefix, just the label
-            let formatted_label = theme.format_label("", label);
-            result.push(format!(" {}: {}", formatted_label, formatted_value));
-        } else {
-            let prefix = if is_last { " └─ " } else { " ├─ " };
-            let colored_prefix = format!("{}{}\x1b[0m", tree_color, prefix);
-            let formatted_label = theme.format_label("", label);
-            result.push(format!("{}{}: {}", colored_prefix, formatted_label, formatted_value));
+/// Prints the fetched lines in the requested format, or with the logo.
+fn show(args: &Args, config: Arc<AppConfig>, cache: &Cache, lines: &[Line]) {
+    let format = args.format.or(args.json.then_some(OutputFormat::Json));
+    if let Some(format) = format {
+        match output::render(format, lines, &config.layout) {
+            Ok(s) => println!("{}", s.trim_end()),
+            Err(e) => {
+                eprintln!("novafetch: {:?} output failed: {}", format, e);
+                std::process::exit(1);
+            }
         }
+        return;
     }
-    result
-}
 
-/// Build tree-structured output from categorized lines.
-fn build_tree_output(
-    lines: &[(String, String, String)],
-    theme: &ThemeManager,
-    no_color: bool,
-) -> Vec<String> {
-    let mut result = Vec::new();
-    let tree_color = if no_color {
-        ""
-    } else {
-        "\x1b[38;5;214m" // Orange/Yellow for tree structure
+    let display_lines = format_lines(lines, &config, args.no_color);
+
+    // Logo slug; its palette may also color the labels (theme.colors_from_logo)
+    let detected = match args.logo.clone().or(config.ascii.distro_override.clone()) {
+        Some(slug) => logos::DetectedLogo { slug, color: None },
+        None => logos::detect_logo(),
     };
+    let slug = detected.slug.trim();
+    let slug = if slug.is_empty() { "fallback" } else { slug };
+    let (_, builtin_palette) = logos::get_logo(slug, false);
+    let distro_palette = logos::with_color_hint(builtin_palette, detected.color);
+    let mut config = Arc::unwrap_or_clone(config);
+    logos::derive_theme_colors(&mut config, &distro_palette);
 
-    // Group lines by category
-    let mut hardware = Vec::new();
-    let mut software = Vec::new();
-    let mut status = Vec::new();
-    let mut palette_lines = Vec::new();
+    let icons = IconSet::resolve(&config, lines, cache);
+    let theme = ThemeManager::new(&config, icons, args.no_color);
+    let width = ui::terminal_width(args.width);
 
-    for (key, label, value) in lines {
-        if key == "palette" {
-            palette_lines.push(value.clone());
-            continue;
-        }
-        if let Some(cat) = categorize_key(key) {
-            let display_label = if label.is_empty() {
-                // For user_host, use a default label
-                if key == "user_host" {
-                    "Host".to_string()
-                } else {
-                    key.to_string()
-                }
-            } else {
-                label.clone()
-            };
-            match cat {
-                Category::Hardware => hardware.push((display_label, value.clone())),
-                Category::Software => software.push((display_label, value.clone())),
-                Category::Status => status.push((display_label, value.clone())),
-            }
-        }
-    }
+    // Optional: image logo
+    let use_image = config
+        .general
+        .image_path
+        .as_ref()
+        .map(|p| p.trim())
+        .filter(|p| !p.is_empty())
+        .map(|p| config::expand_tilde(p).to_string_lossy().into_owned());
 
-    // Format each category
-    let header_width = 24; // Width for category headers
+    // Info lines for an info column `width` wide (None: not cut)
+    let info_lines_for = |width: Option<usize>| {
+        build_info_lines(&display_lines, &config, &theme, args.no_color, width)
+    };
 
-    if !hardware.is_empty() {
-        let header = Category::Hardware.header(header_width);
-        let colored_header = if no_color {
-            header
-        } else {
-            format!("{}{}\x1b[0m", tree_color, header)
+    if let Some(path) = use_image.as_deref() {
+        let shown = match TextRenderer::parse(&config.general.image_renderer) {
+            Some(renderer) => print_text_image_logo(
+                path,
+                &info_lines_for,
+                &config,
+                renderer,
+                cache,
+                args.no_color,
+                width,
+            ),
+            None => print_image_logo(path, &info_lines_for, &config, width),
         };
-        result.push(colored_header);
-        result.extend(format_group(&hardware, theme, tree_color));
-        result.push(String::new()); // Empty line between categories
+        if shown {
+            return;
+        }
+        eprintln!("novafetch: image '{}' failed, using ASCII logo", path);
     }
 
-    if !software.is_empty() {
-        let header = Category::Software.header(header_width);
-        let colored_header = if no_color {
-            header
+    // ASCII logo (if enabled)
+    if config.ascii.print_ascii {
+        // Build info lines (tree sections or flat); cut to the column once the logo is known
+        let info_lines = info_lines_for(None);
+        let layout = LogoLayout::from_config(&config.ascii);
+
+        let info_height = ui::trim_blank_tail(&info_lines).len();
+        let small = logos::use_small(&config.ascii.size, slug, info_height, &layout, width);
+        let (builtin_art, _) = logos::get_logo(slug, small);
+        let user_art = logos::user_logo(&config.ascii, slug);
+        // Built-in markers are only honored in distro mode; user logos always use theirs
+        let use_markers = logos::is_distro_mode(&config)
+            || user_art.as_deref().is_some_and(logos::has_color_markers);
+        let art = user_art.unwrap_or_else(|| builtin_art.join("\n"));
+        let logo_lines: Vec<String> = if use_markers {
+            let palette = logos::marker_palette(&config, &distro_palette);
+            logos::paint_logo(&art, &palette, args.no_color)
         } else {
-            format!("{}{}\x1b[0m", tree_color, header)
+            logos::paint_markers(&art, &[], true)
+                .iter()
+                .map(|s| {
+                    if args.no_color {
+                        s.to_string()
+                    } else {
+                        let rgb = config.theme.primary_color;
+                        crate::ui::gradient::create_gradient_text(
+                            s,
+                            (rgb[0], rgb[1], rgb[2]),
+                            (
+                                config.theme.secondary_color[0],
+                                config.theme.secondary_color[1],
+                                config.theme.secondary_color[2],
+                            ),
+                        )
+                    }
+                })
+                .collect()
         };
-        result.push(colored_header);
-        result.extend(format_group(&software, theme, tree_color));
-        result.push(String::new());
-    }
 
-    if !status.is_empty() {
-        let header = Category::Status.header(header_width);
-        let colored_header = if no_color {
-            header
-        } else {
-            format!("{}{}\x1b[0m", tree_color, header)
-        };
-        result.push(colored_header);
-        result.extend(format_group(&status, theme, tree_color));
-        result.push(String::new());
+        let logo_width = logo_lines.iter().map(|l| ui::display_width(l)).max();
+        let info_lines = info_lines_for(layout.info_columns(logo_width.unwrap_or(0), width));
+        ui::print_final_result(&logo_lines, &info_lines, &layout, width);
+    } else {
+        // No ASCII: print info lines only
+        let info_lines = info_lines_for(width.map(|w| w.saturating_sub(config.ascii.padding)));
+        ui::print_lines(&info_lines, config.ascii.padding, width);
     }
+}
 
-    // Add palette lines at the very end if present
-    if !palette_lines.is_empty() {
-        for palette_line in palette_lines {
-            result.push(theme.format_value(&palette_line));
+/// Runs a `novafetch config` subcommand on the `--config` file (default: the XDG path).
+/// Returns the exit status.
+fn run_config_command(action: &ConfigCommand, path: Option<&Path>, overrides: &Overrides) -> i32 {
+    let Some(path) = path.map(Path::to_path_buf).or_else(AppConfig::default_path) else {
+        eprintln!("novafetch: no config directory found");
+        return 1;
+    };
+    match action {
+        ConfigCommand::Init { force } => {
+            if path.exists() && !force {
+                eprintln!(
+                    "novafetch: {} already exists (use --force to replace it)",
+                    path.display()
+                );
+                return 1;
+            }
+            match AppConfig::default().write_to(&path) {
+                Ok(()) => {
+                    println!("Wrote {}", path.display());
+                    0
+                }
+                Err(e) => {
+                    eprintln!("novafetch: cannot write {}: {}", path.display(), e);
+                    1
+                }
+            }
+        }
+        ConfigCommand::PrintDefault => {
+            print!("{}", config::DEFAULT_CONFIG);
+            0
+        }
+        ConfigCommand::PrintEffective => {
+            // A missing file is not created here
+            let loaded = AppConfig::load_layers(&path, overrides);
+            for problem in &loaded.problems {
+                eprintln!("novafetch: {}", problem);
+            }
+            match loaded.config.to_toml() {
+                Ok(s) => {
+                    print!("{}", s);
+                    0
+                }
+                Err(e) => {
+                    eprintln!("novafetch: cannot print the config: {}", e);
+                    1
+                }
+            }
+        }
+        ConfigCommand::Path => {
+            println!("{}", path.display());
+            0
         }
+        ConfigCommand::Edit => {
+            if !path.exists() {
+                if let Err(e) = AppConfig::default().write_to(&path) {
+                    eprintln!("novafetch: cannot write {}: {}", path.display(), e);
+                    return 1;
+                }
+            }
+            match config::open_in_editor(&path) {
+                Ok(status) if status.success() => check_config(&path, overrides),
+                Ok(status) => {
+                    eprintln!("novafetch: editor exited with {}", status);
+                    1
+                }
+                Err(e) => {
+                    eprintln!("novafetch: cannot start the editor: {}", e);
+                    1
+                }
+            }
+        }
+        ConfigCommand::Check => check_config(&path, overrides),
+        ConfigCommand::Migrate {
+            file,
+            from,
+            write,
+            force,
+        } => migrate_config(file, *from, &path, *write, *force),
     }
-
-    result
 }
 
-/// Convert lines to JSON map (for --json).
-fn lines_to_json(
-    lines: &[(String, String, String)],
-    sys: Option<&System>,
-    config: &AppConfig,
-) -> HashMap<String, serde_json::Value> {
-    let mut map: HashMap<String, serde_json::Value> = lines
-        .iter()
-        .map(|(_k, l, v)| {
-            let key = if l.is_empty() {
-                "user_host".to_string()
-            } else {
-                l.to_string()
-            };
-            (key, serde_json::Value::String(v.clone()))
-        })
-        .collect();
-    if let Some(s) = sys {
-        if config.layout.contains(&"memory".to_string()) {
-            map.insert(
-                "memory_used_bytes".to_string(),
-                serde_json::Value::Number(serde_json::Number::from(s.used_memory())),
-            );
-            map.insert(
-                "memory_total_bytes".to_string(),
-                serde_json::Value::Number(serde_json::Number::from(s.total_memory())),
-            );
+/// `config migrate`: converts `file` and prints the result or writes it to `path`.
+fn migrate_config(
+    file: &Path,
+    from: Option<migrate::Source>,
+    path: &Path,
+    write: bool,
+    force: bool,
+) -> i32 {
+    if write && path.exists() && !force {
+        eprintln!(
+            "novafetch: {} already exists (use --force to replace it)",
+            path.display()
+        );
+        return 1;
+    }
+    let text = match std::fs::read_to_string(file) {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("novafetch: cannot read {}: {}", file.display(), e);
+            return 1;
         }
-        if config.layout.contains(&"swap".to_string()) {
-            map.insert(
-                "swap_used_bytes".to_string(),
-                serde_json::Value::Number(serde_json::Number::from(s.used_swap())),
-            );
-            map.insert(
-                "swap_total_bytes".to_string(),
-                serde_json::Value::Number(serde_json::Number::from(s.total_swap())),
-            );
+    };
+    let source = from.unwrap_or_else(|| migrate::Source::detect(file, &text));
+    let migration = match migrate::migrate(source, &text) {
+        Ok(m) => m,
+        Err(e) => {
+            eprintln!("novafetch: {}: {}", file.display(), e);
+            return 1;
+        }
+    };
+    for note in &migration.notes {
+        eprintln!("novafetch: not migrated: {}", note);
+    }
+    if !write {
+        return match migration.config.to_toml() {
+            Ok(toml) => {
+                print!("{}", toml);
+                0
+            }
+            Err(e) => {
+                eprintln!("novafetch: {}", e);
+                1
+            }
+        };
+    }
+    match migration.config.write_to(path) {
+        Ok(()) => {
+            println!("Wrote {}", path.display());
+            0
+        }
+        Err(e) => {
+            eprintln!("novafetch: cannot write {}: {}", path.display(), e);
+            1
         }
     }
-    map
 }
 
-fn main() {
-    let args = Args::parse();
-    let config = AppConfig::load(args.config.as_deref());
-
-    let need_sys = config.layout.iter().any(|k| {
-        k.as_str() == "memory" || k.as_str() == "cpu" || k.as_str() == "swap"
-    });
-    let mut sys = need_sys.then(system_for_fetch);
-    if let Some(ref mut s) = sys {
-        std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
-        s.refresh_cpu_usage();
+/// `config check`: prints the problems in all config layers and profiles; 1 if there are any.
+fn check_config(path: &Path, overrides: &Overrides) -> i32 {
+    let loaded = AppConfig::load_layers(path, overrides);
+    // Profiles are checked even when this run does not use them
+    let mut problems = loaded.problems;
+    problems.extend(loaded.profile_problems);
+    if loaded.files.is_empty() && problems.is_empty() {
+        eprintln!("novafetch: cannot read {}: no config file", path.display());
+        return 1;
     }
-    let lines = collect_lines(&config, sys.as_ref(), args.no_color);
-
-    if args.json {
-        let map = lines_to_json(&lines, sys.as_ref(), &config);
-        match serde_json::to_string_pretty(&map) {
-            Ok(s) => println!("{}", s),
-            Err(e) => eprintln!("json error: {}", e),
+    if problems.is_empty() {
+        for file in &loaded.files {
+            println!("{}: OK", file.display());
         }
-        return;
+        return 0;
     }
+    for problem in &problems {
+        eprintln!("{}", problem);
+    }
+    eprintln!(
+        "{} problem{} found",
+        problems.len(),
+        if problems.len() == 1 { "" } else { "s" }
+    );
+    1
+}
 
-    let theme = ThemeManager::new(&config, args.no_color);
-
-    // Optional: image logo
-    let use_image = config
-        .general
-        .image_path
-        .as_ref()
-        .map(|p| p.trim())
-        .filter(|p| !p.is_empty());
-
-    if let Some(path) = use_image {
-        if let Ok(()) = image_render::print_image(path, config.general.image_width) {
-            println!();
-            let info_lines = build_tree_output(&lines, &theme, args.no_color);
-            for line in info_lines {
-                println!("{}", line);
-            }
-            return;
+/// Prints the image converted to text like an ASCII logo. Beside the info it is fitted to the
+/// info height; above or below only `image_width` limits it. False if the image cannot be read.
+fn print_text_image_logo(
+    path: &str,
+    info_lines_for: &dyn Fn(Option<usize>) -> Vec<String>,
+    config: &AppConfig,
+    renderer: TextRenderer,
+    cache: &Cache,
+    no_color: bool,
+    width: Option<usize>,
+) -> bool {
+    let layout = LogoLayout::from_config(&config.ascii);
+    let max_height = match layout.position {
+        Position::Left | Position::Right => ui::trim_blank_tail(&info_lines_for(None)).len(),
+        Position::Top | Position::Bottom => usize::MAX,
+    };
+    let depth = if no_color {
+        ColorDepth::None
+    } else {
+        ColorDepth::parse(&config.general.image_colors)
+    };
+    let image_width = config.general.image_width;
+    match image_text::text_logo(path, image_width, max_height, renderer, depth, cache) {
+        Ok(logo_lines) => {
+            let logo_width = logo_lines.iter().map(|l| ui::display_width(l)).max();
+            let info_lines = info_lines_for(layout.info_columns(logo_width.unwrap_or(0), width));
+            ui::print_final_result(&logo_lines, &info_lines, &layout, width);
+            true
+        }
+        Err(e) => {
+            eprintln!("novafetch: cannot convert image '{}': {}", path, e);
+            false
         }
-        eprintln!("novafetch: image '{}' failed, using ASCII logo", path);
     }
+}
 
-    // ASCII logo (if enabled)
-    let slug: String = args
-        .logo
-        .clone()
-        .or(config.ascii.distro_override.clone())
-        .unwrap_or_else(distro_slug);
-    let slug = slug.trim();
-    let slug = if slug.is_empty() { "fallback" } else { slug };
-
-    if config.ascii.print_ascii {
-        let (logo_lines_vec, _primary) = logos::get_logo(slug);
-        let logo_lines: Vec<String> = logo_lines_vec
-            .iter()
-            .map(|s| {
-                if args.no_color {
-                    s.to_string()
-                } else {
-                    let rgb = config.theme.primary_color;
-                    crate::ui::gradient::create_gradient_text(
-                        s,
-                        (rgb[0], rgb[1], rgb[2]),
-                        (
-                            config.theme.secondary_color[0],
-                            config.theme.secondary_color[1],
-                            config.theme.secondary_color[2],
-                        ),
-                    )
+/// Prints the image logo beside, above or below the info lines. Side by side needs a terminal
+/// and enough width; otherwise the image goes on top. False if the image cannot be shown.
+fn print_image_logo(
+    path: &str,
+    info_lines_for: &dyn Fn(Option<usize>) -> Vec<String>,
+    config: &AppConfig,
+    width: Option<usize>,
+) -> bool {
+    let layout = LogoLayout::from_config(&config.ascii);
+    let image_width = config.general.image_width;
+    let beside = matches!(layout.position, Position::Left | Position::Right)
+        && std::io::stdout().is_terminal();
+    if beside {
+        // The image is fitted to the height of the info block
+        let info_height = ui::trim_blank_tail(&info_lines_for(None)).len();
+        match image_render::load_image(path, image_width, info_height) {
+            Ok(logo) if layout.fits_beside(logo.width as usize, width) => {
+                let info_lines = info_lines_for(layout.info_columns(logo.width as usize, width));
+                let info = ui::trim_blank_tail(&info_lines);
+                if let Err(e) = image_render::print_beside(&logo, info, &layout, width) {
+                    eprintln!("novafetch: image '{}' failed: {}", path, e);
                 }
-            })
-            .collect();
-
-        // Build tree-structured info lines
-        let info_lines = build_tree_output(&lines, &theme, args.no_color);
-
-        ui::print_final_result(&logo_lines, &info_lines, 4);
-    } else {
-        // No ASCII: print tree-structured info lines only
-        let info_lines = build_tree_output(&lines, &theme, args.no_color);
-        for line in info_lines {
-            println!("{}", line);
+                return true;
+            }
+            Ok(_) => {}
+            Err(_) => return false,
+        }
+    }
+    let info_lines = &info_lines_for(width.map(|w| w.saturating_sub(layout.padding)));
+    if layout.position == Position::Bottom {
+        if image_render::check_image(path).is_err() {
+            return false;
         }
+        ui::print_lines(ui::trim_blank_tail(info_lines), layout.padding, width);
+        println!();
+        if let Err(e) = image_render::print_image(path, image_width, layout.padding) {
+            eprintln!("novafetch: image '{}' failed: {}", path, e);
+        }
+        return true;
+    }
+    if image_render::print_image(path, image_width, layout.padding).is_err() {
+        return false;
     }
+    println!();
+    ui::print_lines(info_lines, layout.padding, width);
+    true
 }
@@ -2,18 +2,18 @@
 
 use std::process::Command;
 
-/// Prefer lspci (or platform equivalent) for the cleanest model name; fall back to sysinfo components.
-/// Sysinfo fallback skips sensor labels (junction, edge, mem, sensor, fan) so "amdgpu junction" is never shown.
-pub fn get_gpu_name() -> Option<String> {
-    if let Some(name) = try_lspci() {
-        return Some(name);
+/// Prefer lspci (or platform equivalent) for the cleanest model names, one per GPU; fall back to
+/// sysinfo components. Sysinfo fallback skips sensor labels (junction, edge, mem, sensor, fan)
+/// so "amdgpu junction" is never shown. Empty when nothing was found.
+pub fn get_gpu_names() -> Vec<String> {
+    let names = try_lspci();
+    if !names.is_empty() {
+        return names;
     }
-    if let Some(name) = try_sysinfo_components() {
-        if !is_generic(&name) {
-            return Some(name);
-        }
-    }
-    None
+    try_sysinfo_components()
+        .filter(|name| !is_generic(name))
+        .into_iter()
+        .collect()
 }
 
 fn is_generic(s: &str) -> bool {
@@ -63,33 +63,36 @@ fn try_sysinfo_components() -> Option<String> {
 }
 
 #[cfg(target_os = "linux")]
-fn try_lspci() -> Option<String> {
-    let out = Command::new("lspci")
+fn try_lspci() -> Vec<String> {
+    let Some(out) = Command::new("lspci")
         .output()
         .ok()
-        .filter(|o| o.status.success())?;
+        .filter(|o| o.status.success())
+    else {
+        return Vec::new();
+    };
     let s = String::from_utf8_lossy(&out.stdout);
+    let mut names = Vec::new();
     for line in s.lines() {
         let line = line.trim();
         if line.to_lowercase().contains("vga")
             || line.to_lowercase().contains("3d")
             || line.to_lowercase().contains("display")
         {
-            let after_first = line.splitn(2, ':').nth(1).unwrap_or(line).trim();
+            let after_first = line.split_once(':').map_or(line, |x| x.1).trim();
             let name = after_first
-                .splitn(2, ':')
-                .nth(1)
-                .unwrap_or(after_first)
+                .split_once(':')
+                .map_or(after_first, |x| x.1)
                 .trim();
             if !name.is_empty() && !is_generic(name) {
-                return Some(crate::info::clean_gpu_name(name));
+                names.push(crate::info::clean_gpu_name(name));
             }
         }
     }
-    None
+    names
 }
 
 #[cfg(not(target_os = "linux"))]
-fn try_lspci() -> Option<String> {
-    None
+fn try_lspci() -> Vec<String> {
+    Vec::new()
 }
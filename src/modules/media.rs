@@ -1,16 +1,71 @@
 //! Active media player status via MPRIS (Spotify, VLC, etc.).
 
-/// Returns current track as "🎵 Artist - Song", or None if no player or DBus unavailable.
-pub fn get_media_status() -> Option<String> {
+use crate::info::{Category, FetchContext, FieldSpec, FormatContext, InfoItem, Module};
+
+/// Returns current track (artist, title, player), or None if no player or DBus unavailable.
+pub fn get_media_status() -> Option<InfoItem> {
     let finder = mpris::PlayerFinder::new().ok()?;
     let player = finder.find_active().ok()?;
     let metadata = player.get_metadata().ok()?;
 
-    let artist = metadata
-        .artists()
-        .and_then(|a| a.first().copied())
-        .unwrap_or("Unknown");
-    let title = metadata.title().unwrap_or("Unknown");
+    let artist = metadata.artists().and_then(|a| a.first().copied());
+    let title = metadata.title();
+
+    Some(
+        InfoItem::new("Media")
+            .with_opt("artist", artist)
+            .with_opt("title", title)
+            .with("player", player.identity()),
+    )
+}
+
+const FIELDS: &[FieldSpec] = &[
+    FieldSpec::text("artist", "Artist of the current track"),
+    FieldSpec::text("title", "Title of the current track"),
+    FieldSpec::text("player", "MPRIS player identity"),
+];
+
+pub struct Media;
+
+impl Module for Media {
+    fn key(&self) -> &'static str {
+        "media"
+    }
+
+    fn label(&self) -> &'static str {
+        "Media"
+    }
+
+    fn category(&self) -> Option<Category> {
+        Some(Category::Status)
+    }
+
+    fn icon(&self) -> &'static str {
+        "\u{f001}" // fa-music
+    }
+
+    fn unicode_icon(&self) -> &'static str {
+        "♫"
+    }
+
+    fn emoji_icon(&self) -> &'static str {
+        "🎵"
+    }
+
+    fn fields(&self) -> &'static [FieldSpec] {
+        FIELDS
+    }
+
+    fn fetch(&self, _ctx: &FetchContext) -> Vec<InfoItem> {
+        get_media_status().into_iter().collect()
+    }
 
-    Some(format!("🎵 {} - {}", artist, title))
+    /// "🎵 Artist - Song"
+    fn format(&self, item: &InfoItem, _ctx: &FormatContext) -> String {
+        format!(
+            "🎵 {} - {}",
+            item.text("artist").unwrap_or("Unknown"),
+            item.text("title").unwrap_or("Unknown")
+        )
+    }
 }
@@ -1,11 +1,55 @@
 //! Local IP address detection.
 
-/// Returns the local IPv4 address as a string, or None if unavailable.
+use crate::info::{Category, FetchContext, FieldSpec, FormatContext, InfoItem, Module};
+
+/// Returns the local IP address as a string, or None if unavailable.
 pub fn get_local_ip() -> Option<String> {
     let ip = local_ip_address::local_ip().ok()?;
-    if ip.is_ipv4() {
-        Some(ip.to_string())
-    } else {
-        Some(ip.to_string())
+    Some(ip.to_string())
+}
+
+const FIELDS: &[FieldSpec] = &[
+    FieldSpec::text("address", "Local IP address"),
+];
+
+pub struct LocalIp;
+
+impl Module for LocalIp {
+    fn key(&self) -> &'static str {
+        "local_ip"
+    }
+
+    fn label(&self) -> &'static str {
+        "Local IP"
+    }
+
+    fn category(&self) -> Option<Category> {
+        Some(Category::Status)
+    }
+
+    fn icon(&self) -> &'static str {
+        "\u{f0ac}" // fa-globe
+    }
+
+    fn unicode_icon(&self) -> &'static str {
+        "⇅"
+    }
+
+    fn emoji_icon(&self) -> &'static str {
+        "🌐"
+    }
+
+    fn fields(&self) -> &'static [FieldSpec] {
+        FIELDS
+    }
+
+    fn fetch(&self, _ctx: &FetchContext) -> Vec<InfoItem> {
+        get_local_ip()
+            .map(|ip| vec![InfoItem::new("Local IP").with("address", ip)])
+            .unwrap_or_default()
+    }
+
+    fn format(&self, item: &InfoItem, _ctx: &FormatContext) -> String {
+        item.text("address").unwrap_or_default().to_string()
     }
 }
@@ -0,0 +1,134 @@
+//! Flat `KEY=value` output (`--format env`) and its shell-eval variant (`--format shell`).
+//!
+//! Fields become `NOVAFETCH_<MODULE>_<FIELD>`; multi-item modules are indexed
+//! (`NOVAFETCH_DISK_0_MOUNT`) and get a `NOVAFETCH_DISK_COUNT`. Modules that produced
+//! nothing are left out; `NOVAFETCH_TIMED_OUT` lists timed-out keys separated by spaces.
+//! Keys that map to the same name (custom modules `foo-bar` and `foo_bar`) are an error
+//! rather than one silently overwriting the other.
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+const PREFIX: &str = "NOVAFETCH";
+
+/// Renders the `--json` document as one assignment per line.
+/// `export` = shell syntax with single-quoted values, safe for `eval`.
+pub fn render(doc: &Value, export: bool) -> Result<String, String> {
+    // (variable, value, key it came from)
+    let mut vars = Vec::new();
+    if let Some(v) = doc.get("schema_version") {
+        flatten(
+            &var_name(PREFIX, "schema_version"),
+            v,
+            "schema_version",
+            &mut vars,
+        );
+    }
+    if let Some(Value::Object(modules)) = doc.get("modules") {
+        for (key, value) in modules {
+            flatten(&var_name(PREFIX, key), value, key, &mut vars);
+        }
+    }
+    let timed_out: Vec<&str> = doc
+        .get("timed_out")
+        .and_then(Value::as_array)
+        .map(|keys| keys.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+    vars.push((
+        var_name(PREFIX, "timed_out"),
+        timed_out.join(" "),
+        "timed_out",
+    ));
+
+    let mut seen = HashMap::new();
+    for (name, _, origin) in &vars {
+        if let Some(other) = seen.insert(name.as_str(), *origin) {
+            return Err(if other == *origin {
+                format!("\"{}\" maps to {} twice", origin, name)
+            } else {
+                format!(
+                    "\"{}\" and \"{}\" both map to {}; rename one of the custom modules",
+                    other, origin, name
+                )
+            });
+        }
+    }
+
+    let mut out = String::new();
+    for (name, value, _) in vars {
+        if export {
+            out.push_str(&format!("export {}={}\n", name, shell_quote(&value)));
+        } else {
+            // One variable per line: embedded newlines would start a bogus entry
+            out.push_str(&format!("{}={}\n", name, value.replace(['\n', '\r'], " ")));
+        }
+    }
+    Ok(out)
+}
+
+fn flatten<'a>(
+    name: &str,
+    value: &Value,
+    origin: &'a str,
+    out: &mut Vec<(String, String, &'a str)>,
+) {
+    match value {
+        Value::Null => {}
+        Value::Object(map) => {
+            for (k, v) in map {
+                flatten(&var_name(name, k), v, origin, out);
+            }
+        }
+        Value::Array(items) => {
+            out.push((var_name(name, "count"), items.len().to_string(), origin));
+            for (i, v) in items.iter().enumerate() {
+                flatten(&var_name(name, &i.to_string()), v, origin, out);
+            }
+        }
+        Value::String(s) => out.push((name.to_string(), s.clone(), origin)),
+        other => out.push((name.to_string(), other.to_string(), origin)),
+    }
+}
+
+/// `prefix` + `_` + `part` upper-cased, with anything outside `[A-Z0-9_]` replaced by `_`.
+fn var_name(prefix: &str, part: &str) -> String {
+    let part: String = part
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("{}_{}", prefix, part)
+}
+
+/// Single-quotes `s` for POSIX shells; embedded `'` become `'\''`.
+fn shell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', r"'\''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn colliding_variable_names_are_an_error() {
+        let doc = json!({
+            "modules": {"foo-bar": {"value": "a"}, "foo_bar": {"value": "b"}},
+            "timed_out": [],
+        });
+        let err = render(&doc, false).unwrap_err();
+        assert!(err.contains("NOVAFETCH_FOO_BAR_VALUE"), "{}", err);
+
+        let doc = json!({"modules": {"foo-bar": {"value": "a"}}, "timed_out": []});
+        assert_eq!(
+            render(&doc, false).unwrap(),
+            "NOVAFETCH_FOO_BAR_VALUE=a\nNOVAFETCH_TIMED_OUT=\n"
+        );
+    }
+}
@@ -0,0 +1,164 @@
+//! Versioned JSON output (`--json`) and its JSON Schema (`--json-schema`).
+//!
+//! Document layout (schema_version 1):
+//! - `schema_version`: bumped on any incompatible change.
+//! - `novafetch_version`: crate version that produced the document.
+//! - `modules`: keyed by layout key. Single-item modules are one object of typed fields;
+//!   multi-item modules (disk, resolution, gpu, palette) are arrays of such objects, leaving
+//!   out items without fields. A module that produced nothing, or no fields, is `null` (`[]`
+//!   if multi-item); one that missed its deadline is `null`.
+//! - `timed_out`: layout keys that missed their deadline, in layout order.
+//!
+//! Keys are emitted in sorted order, so output is deterministic.
+
+use serde_json::{json, Map, Value};
+
+use crate::fetch::Line;
+use crate::info::{self, FieldKind, InfoItem, Module};
+
+/// Version of the document layout described above.
+pub const SCHEMA_VERSION: u64 = 1;
+
+/// Builds the `--json` document for the fetched lines; `layout` adds entries for modules
+/// that produced nothing.
+pub fn to_document(lines: &[Line], layout: &[String]) -> Value {
+    let mut modules = Map::new();
+    let mut timed_out = Vec::new();
+
+    for line in lines {
+        let module = line.module;
+        let key = module.key().to_string();
+        let Some(item) = &line.item else {
+            timed_out.push(Value::String(key.clone()));
+            modules.insert(key, Value::Null);
+            continue;
+        };
+        let fields = fields_object(item);
+        if module.multi_item() {
+            let entry = modules
+                .entry(key)
+                .or_insert_with(|| Value::Array(Vec::new()));
+            // A placeholder item without fields (e.g. no display detected) is not an entry
+            if let (Value::Array(items), false) = (entry, item.fields.is_empty()) {
+                items.push(fields);
+            }
+        } else {
+            // Single-item modules with several lines (theme) merge their fields into one object
+            let entry = modules
+                .entry(key)
+                .or_insert_with(|| Value::Object(Map::new()));
+            if let (Value::Object(obj), Value::Object(new)) = (entry, fields) {
+                obj.extend(new);
+            }
+        }
+    }
+
+    // No fields is no value, as for a module that produced nothing
+    for value in modules.values_mut() {
+        if value.as_object().is_some_and(Map::is_empty) {
+            *value = Value::Null;
+        }
+    }
+    for module in layout.iter().filter_map(|k| info::find_module(k.trim())) {
+        let empty = if module.multi_item() {
+            Value::Array(Vec::new())
+        } else {
+            Value::Null
+        };
+        modules.entry(module.key()).or_insert(empty);
+    }
+
+    json!({
+        "schema_version": SCHEMA_VERSION,
+        "novafetch_version": env!("CARGO_PKG_VERSION"),
+        "modules": modules,
+        "timed_out": timed_out,
+    })
+}
+
+fn fields_object(item: &InfoItem) -> Value {
+    Value::Object(
+        item.fields
+            .iter()
+            .map(|(name, field)| {
+                (
+                    name.clone(),
+                    serde_json::to_value(field).unwrap_or_default(),
+                )
+            })
+            .collect(),
+    )
+}
+
+/// JSON Schema (draft 2020-12) for `to_document`, generated from the module registry.
+pub fn schema() -> Value {
+    let modules: Map<String, Value> = info::registry()
+        .iter()
+        .map(|m| (m.key().to_string(), module_schema(*m)))
+        .collect();
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "NovaFetch JSON output",
+        "type": "object",
+        "required": ["schema_version", "novafetch_version", "modules", "timed_out"],
+        "properties": {
+            "schema_version": { "const": SCHEMA_VERSION },
+            "novafetch_version": { "type": "string" },
+            "modules": {
+                "type": "object",
+                "properties": modules,
+            },
+            "timed_out": {
+                "type": "array",
+                "items": { "type": "string" },
+            },
+        },
+    })
+}
+
+fn module_schema(module: &dyn Module) -> Value {
+    let properties: Map<String, Value> = module
+        .fields()
+        .iter()
+        .map(|f| {
+            let ty = match f.kind {
+                FieldKind::Int => "integer",
+                FieldKind::Float => "number",
+                FieldKind::Text => "string",
+            };
+            (
+                f.name.to_string(),
+                json!({ "type": ty, "description": f.description }),
+            )
+        })
+        .collect();
+    let object = json!({
+        "type": "object",
+        "properties": properties,
+        "additionalProperties": false,
+    });
+    if module.multi_item() {
+        json!({ "type": ["array", "null"], "items": object })
+    } else {
+        json!({ "anyOf": [object, { "type": "null" }] })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn module_without_fields_is_null() {
+        let de = info::find_module("de").unwrap();
+        let lines = [Line {
+            module: de,
+            item: Some(InfoItem::new("DE/WM")),
+        }];
+        let doc = to_document(&lines, &["de".into(), "kernel".into()]);
+        assert_eq!(doc["modules"]["de"], Value::Null);
+        assert_eq!(doc["modules"]["kernel"], Value::Null);
+        assert_eq!(doc["timed_out"], json!([]));
+    }
+}
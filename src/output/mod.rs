@@ -0,0 +1,64 @@
+//! Machine-readable output formats. All but Prometheus serialize the `--json` document
+//! (`json::to_document`), so field names and values are identical across formats.
+
+pub mod env;
+pub mod json;
+pub mod prometheus;
+pub mod yaml;
+
+use clap::ValueEnum;
+use serde_json::Value;
+
+use crate::fetch::Line;
+
+/// `--format` values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Json,
+    Yaml,
+    Toml,
+    /// `NOVAFETCH_CPU_MODEL=...` lines
+    Env,
+    /// `export NOVAFETCH_CPU_MODEL='...'` lines, for `eval "$(novafetch --format shell)"`
+    Shell,
+    /// Prometheus text exposition format (gauges + info metrics)
+    Prometheus,
+}
+
+/// Renders the fetched lines in `format`.
+pub fn render(format: OutputFormat, lines: &[Line], layout: &[String]) -> Result<String, String> {
+    let doc = || json::to_document(lines, layout);
+    match format {
+        OutputFormat::Json => serde_json::to_string_pretty(&doc()).map_err(|e| e.to_string()),
+        OutputFormat::Yaml => Ok(yaml::render(&doc())),
+        OutputFormat::Toml => {
+            // TOML has no null: modules that produced nothing are left out
+            let table = match to_toml(doc()) {
+                Some(toml::Value::Table(t)) => t,
+                _ => toml::Table::new(),
+            };
+            toml::to_string(&table).map_err(|e| e.to_string())
+        }
+        OutputFormat::Env => env::render(&doc(), false),
+        OutputFormat::Shell => env::render(&doc(), true),
+        OutputFormat::Prometheus => Ok(prometheus::render(lines)),
+    }
+}
+
+fn to_toml(value: Value) -> Option<toml::Value> {
+    Some(match value {
+        Value::Null => return None,
+        Value::Bool(b) => toml::Value::Boolean(b),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => toml::Value::Integer(i),
+            None => toml::Value::Float(n.as_f64()?),
+        },
+        Value::String(s) => toml::Value::String(s),
+        Value::Array(items) => toml::Value::Array(items.into_iter().filter_map(to_toml).collect()),
+        Value::Object(map) => toml::Value::Table(
+            map.into_iter()
+                .filter_map(|(k, v)| Some((k, to_toml(v)?)))
+                .collect(),
+        ),
+    })
+}
@@ -0,0 +1,143 @@
+//! Prometheus text exposition format (`--format prometheus`, `novafetch serve`).
+//!
+//! Every numeric field becomes a gauge `novafetch_<module>_<field>` (HELP = field description);
+//! items of multi-item modules are told apart by their text fields as labels
+//! (`novafetch_disk_used_bytes{fs="ext4",mount="/"}`). Text fields are also exported as
+//! `novafetch_<module>_info{...} 1`, and modules that missed their deadline as
+//! `novafetch_module_timed_out{module="..."} 1`.
+
+use crate::fetch::Line;
+use crate::info::{Field, FieldKind, InfoItem, Module};
+
+/// Content-Type of the text format (version 0.0.4).
+pub const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Modules left out: palette is purely visual, media would create a series per track.
+const SKIPPED: &[&str] = &["palette", "media"];
+
+/// Renders the fetched lines as Prometheus metrics.
+pub fn render(lines: &[Line]) -> String {
+    // Group items per module in layout order; single-item modules (theme) merge into one item
+    let mut groups: Vec<(&'static dyn Module, Vec<InfoItem>)> = Vec::new();
+    let mut timed_out = Vec::new();
+    for line in lines {
+        let module = line.module;
+        let Some(item) = &line.item else {
+            timed_out.push(module.key());
+            continue;
+        };
+        if SKIPPED.contains(&module.key()) {
+            continue;
+        }
+        let idx = match groups.iter().position(|(m, _)| m.key() == module.key()) {
+            Some(idx) => idx,
+            None => {
+                groups.push((module, Vec::new()));
+                groups.len() - 1
+            }
+        };
+        let items = &mut groups[idx].1;
+        match items.first_mut() {
+            Some(first) if !module.multi_item() => first.fields.extend(item.fields.clone()),
+            _ => items.push(item.clone()),
+        }
+    }
+
+    let mut out = String::new();
+    for (module, items) in &groups {
+        write_module(&mut out, *module, items);
+    }
+    write_header(
+        &mut out,
+        "novafetch_module_timed_out",
+        "Modules that missed their fetch deadline",
+    );
+    for key in timed_out {
+        out.push_str(&format!(
+            "novafetch_module_timed_out{{module=\"{}\"}} 1\n",
+            escape_label(key)
+        ));
+    }
+    out
+}
+
+fn write_module(out: &mut String, module: &dyn Module, items: &[InfoItem]) {
+    let metric = |field: &str| metric_name(&format!("novafetch_{}_{}", module.key(), field));
+
+    let has_text = items
+        .iter()
+        .any(|i| i.fields.iter().any(|(_, f)| matches!(f, Field::Text(_))));
+    if has_text {
+        let name = metric("info");
+        write_header(
+            out,
+            &name,
+            &format!("{} information (value is always 1)", module.label()),
+        );
+        for item in items {
+            out.push_str(&format!("{}{} 1\n", name, text_labels(item)));
+        }
+    }
+
+    for spec in module.fields().iter().filter(|s| s.kind != FieldKind::Text) {
+        let samples: Vec<String> = items
+            .iter()
+            .filter_map(|item| {
+                let value = match item.get(spec.name)? {
+                    Field::Int(n) => n.to_string(),
+                    Field::Float(f) => f.to_string(),
+                    Field::Text(_) => return None,
+                };
+                let labels = if module.multi_item() {
+                    text_labels(item)
+                } else {
+                    String::new()
+                };
+                Some(format!("{}{} {}\n", metric(spec.name), labels, value))
+            })
+            .collect();
+        if samples.is_empty() {
+            continue;
+        }
+        write_header(out, &metric(spec.name), spec.description);
+        out.extend(samples);
+    }
+}
+
+/// `name` with characters not allowed in metric names (custom keys may contain `-`) as `_`.
+fn metric_name(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+fn write_header(out: &mut String, name: &str, help: &str) {
+    let help = help.replace('\\', r"\\").replace('\n', r"\n");
+    out.push_str(&format!(
+        "# HELP {} {}\n# TYPE {} gauge\n",
+        name, help, name
+    ));
+}
+
+/// `{name="value",...}` from the item's text fields; empty string if it has none.
+fn text_labels(item: &InfoItem) -> String {
+    let labels: Vec<String> = item
+        .fields
+        .iter()
+        .filter_map(|(name, field)| match field {
+            Field::Text(s) => Some(format!("{}=\"{}\"", name, escape_label(s))),
+            _ => None,
+        })
+        .collect();
+    if labels.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", labels.join(","))
+    }
+}
+
+fn escape_label(s: &str) -> String {
+    s.replace('\\', r"\\")
+        .replace('"', "\\\"")
+        .replace('\n', r"\n")
+}
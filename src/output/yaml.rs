@@ -0,0 +1,127 @@
+//! YAML output (`--format yaml`), written by hand: the document is plain JSON data, so
+//! block mappings, block sequences and scalars are all it needs.
+//!
+//! Strings are emitted plain when they can't be read back as anything else, and otherwise
+//! double-quoted with JSON escapes (valid YAML escapes as well).
+
+use serde_json::Value;
+
+/// Renders the `--json` document as a YAML block document.
+pub fn render(doc: &Value) -> String {
+    let mut out = String::new();
+    match doc {
+        Value::Object(_) | Value::Array(_) => write_block(doc, 0, &mut out),
+        scalar => {
+            out.push_str(&inline(scalar));
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Writes a non-empty mapping or sequence, one entry per line at `indent`.
+fn write_block(value: &Value, indent: usize, out: &mut String) {
+    let pad = " ".repeat(indent);
+    match value {
+        Value::Object(map) => {
+            for (key, v) in map {
+                out.push_str(&format!("{}{}:", pad, string(key)));
+                // Sequences sit at their key's indent, as serde_yaml wrote them
+                let child = if v.is_array() { indent } else { indent + 2 };
+                write_entry(v, child, out);
+            }
+        }
+        Value::Array(items) => {
+            for v in items {
+                if is_block(v) {
+                    // The nested block's first line keeps its place after the `- `
+                    let mut nested = String::new();
+                    write_block(v, indent + 2, &mut nested);
+                    out.push_str(&format!("{}- {}", pad, &nested[indent + 2..]));
+                } else {
+                    out.push_str(&format!("{}- {}\n", pad, inline(v)));
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Rest of a `key:` line: a nested block below it, or ` value`.
+fn write_entry(value: &Value, indent: usize, out: &mut String) {
+    if is_block(value) {
+        out.push('\n');
+        write_block(value, indent, out);
+    } else {
+        out.push_str(&format!(" {}\n", inline(value)));
+    }
+}
+
+fn is_block(value: &Value) -> bool {
+    match value {
+        Value::Object(map) => !map.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        _ => false,
+    }
+}
+
+/// Scalars and empty collections, which fit on one line.
+fn inline(value: &Value) -> String {
+    match value {
+        Value::Null => "null".to_string(),
+        Value::Bool(b) => b.to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => string(s),
+        Value::Array(_) => "[]".to_string(),
+        Value::Object(_) => "{}".to_string(),
+    }
+}
+
+/// `s` plain if it can only be read back as that string, else double-quoted.
+fn string(s: &str) -> String {
+    const RESERVED: [&str; 11] = [
+        "y", "n", "yes", "no", "on", "off", "true", "false", "null", "~", "nan",
+    ];
+    let plain = s.starts_with(|c: char| c.is_ascii_alphabetic())
+        && s.chars()
+            .all(|c| c.is_ascii_alphanumeric() || "_-.".contains(c))
+        && !RESERVED.contains(&s.to_ascii_lowercase().as_str());
+    if plain {
+        s.to_string()
+    } else {
+        serde_json::to_string(s).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn renders_nested_blocks_and_quotes_ambiguous_strings() {
+        let doc = json!({
+            "modules": {
+                "de": null,
+                "disk": [{"fs": "ext4", "mount": "/"}, {"fs": "no", "mount": "/boot"}],
+                "gpu": [],
+                "kernel": {"version": "Linux 6.1: \"lts\""},
+            },
+            "schema_version": 1,
+        });
+        let expected = "\
+modules:
+  de: null
+  disk:
+  - fs: ext4
+    mount: \"/\"
+  - fs: \"no\"
+    mount: \"/boot\"
+  gpu: []
+  kernel:
+    version: \"Linux 6.1: \\\"lts\\\"\"
+schema_version: 1
+";
+        assert_eq!(render(&doc), expected);
+    }
+}
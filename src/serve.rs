@@ -0,0 +1,151 @@
+//! `novafetch serve`: minimal HTTP endpoint exposing the Prometheus format for scraping.
+//! Each connection is handled on its own thread; every `GET /metrics` runs a fresh fetch. A
+//! module still hanging from an earlier scrape is not started again (see `fetch::collect_lines`).
+
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::cache::Cache;
+use crate::config::AppConfig;
+use crate::fetch::fetch_all;
+use crate::output::prometheus;
+
+/// How long a client may take to send its whole request head before the connection is dropped.
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Largest request line plus headers accepted, in bytes.
+const MAX_REQUEST_HEAD: u64 = 8192;
+
+/// Connections handled at once; further ones are closed right away.
+const MAX_CONNECTIONS: usize = 64;
+
+/// Connections being handled.
+static CONNECTIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// How long a client may take to accept the response before the connection is dropped.
+const WRITE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Binds `listen` (e.g. "127.0.0.1:9184") and serves until the process is killed.
+pub fn run(listen: &str, config: &Arc<AppConfig>, cache: &Cache) -> io::Result<()> {
+    let listener = TcpListener::bind(listen)?;
+    eprintln!(
+        "novafetch: serving metrics on http://{}/metrics",
+        listener.local_addr()?
+    );
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                eprintln!("novafetch: request failed: {}", e);
+                continue;
+            }
+        };
+        if CONNECTIONS.fetch_add(1, Ordering::SeqCst) >= MAX_CONNECTIONS {
+            CONNECTIONS.fetch_sub(1, Ordering::SeqCst);
+            continue;
+        }
+        let (config, cache) = (Arc::clone(config), cache.clone());
+        let spawned = thread::Builder::new()
+            .name("novafetch-serve".into())
+            .spawn(move || {
+                if let Err(e) = handle(stream, &config, &cache) {
+                    eprintln!("novafetch: request failed: {}", e);
+                }
+                CONNECTIONS.fetch_sub(1, Ordering::SeqCst);
+            });
+        if let Err(e) = spawned {
+            CONNECTIONS.fetch_sub(1, Ordering::SeqCst);
+            eprintln!("novafetch: request failed: {}", e);
+        }
+    }
+    Ok(())
+}
+
+/// Reads from the stream until `deadline`, however the client paces its bytes.
+struct DeadlineReader {
+    stream: TcpStream,
+    deadline: Instant,
+}
+
+impl Read for DeadlineReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let remaining = self.deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(io::Error::new(
+                io::ErrorKind::TimedOut,
+                "request took too long",
+            ));
+        }
+        self.stream.set_read_timeout(Some(remaining))?;
+        self.stream.read(buf)
+    }
+}
+
+fn handle(mut stream: TcpStream, config: &Arc<AppConfig>, cache: &Cache) -> io::Result<()> {
+    stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+    let source = DeadlineReader {
+        stream: stream.try_clone()?,
+        deadline: Instant::now() + READ_TIMEOUT,
+    };
+    let mut reader = BufReader::new(source.take(MAX_REQUEST_HEAD));
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    // Skip headers; there is no request body to read for GET
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 {
+            if reader.get_ref().limit() == 0 {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "request head too large",
+                ));
+            }
+            break;
+        }
+        if header.trim().is_empty() {
+            break;
+        }
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or("");
+    let path = parts.next().unwrap_or("").split('?').next().unwrap_or("");
+    let (status, content_type, body) = match (method, path) {
+        ("GET", "/metrics") => {
+            let lines = fetch_all(config, cache);
+            (
+                "200 OK",
+                prometheus::CONTENT_TYPE,
+                prometheus::render(&lines),
+            )
+        }
+        ("GET", "/") => (
+            "200 OK",
+            "text/plain; charset=utf-8",
+            "NovaFetch exporter: metrics are at /metrics\n".to_string(),
+        ),
+        ("GET", _) => (
+            "404 Not Found",
+            "text/plain; charset=utf-8",
+            "not found\n".to_string(),
+        ),
+        _ => (
+            "405 Method Not Allowed",
+            "text/plain; charset=utf-8",
+            "method not allowed\n".to_string(),
+        ),
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        body.len(),
+        body
+    )?;
+    stream.flush()
+}
@@ -1,10 +1,12 @@
 //! Raw ASCII art constants for distro logos.
 //! High-quality ASCII art for all supported distributions.
+//! Art may contain `${c1}`..`${c6}` color markers for `theme.mode = "distro"` (palettes in `logos`);
+//! art without markers is drawn in the palette's first color.
 
 // --- Major families ---
 
 pub const ARCH: &str = r#"
-                   -`
+${c1}                   -`
                   .o+`
                  `ooo/
                 `+oooo:
@@ -15,7 +17,7 @@ pub const ARCH: &str = r#"
            `/++++++++++++++:
           `/+++ooooooooooooo/`
          ./ooosssso++osssssso+`
-        .oossssso-````/ossssss+`
+${c2}        .oossssso-````/ossssss+`
        -osssssso.      :ssssssso.
       :osssssss/        osssso+++.
      /ossssssss/        +ssssooo/-
@@ -26,10 +28,723 @@ pub const ARCH: &str = r#"
 "#;
 
 pub const DEBIAN: &str = r#"
-       _,met$$$$$gg.
+${c1}       _,met$$$$$gg.
     ,g$$$$$$$$$$$$$$$P.
   ,g$$P"     """Y$$.".
  ,$$P'              `$$$.
 ',$$P       ,ggs.     `$$b:
-`d$$'     ,$P"'   .    $$$
- $$P      d$'     ,
\ No newline at end of file
+`d$$'     ,$P"'   ${c2}.    ${c1}$$$
+ $$P      d$'     ${c2},    ${c1}$$P
+ $$:      $$.   ${c2}-    ${c1},d$$'
+ $$;      Y$b._   _,d$P'
+ Y$$.    ${c2}`.${c1}`"Y$$$$P"'
+ `$$b      ${c2}"-.__
+${c1}  `Y$$
+   `Y$$.
+     `$$b.
+       `Y$$b.
+          `"Y$b._
+              `"""
+"#;
+
+pub const UBUNTU: &str = r#"
+${c1}            .-/+oossssoo+/-.
+        `:+ssssssssssssssssss+:`
+      -+ssssssssssssssssssyyssss+-
+    .ossssssssssssssssss${c2}dMMMNy${c1}sssso.
+   /sssssssssss${c2}hdmmNNmmyNMMMMh${c1}ssssss/
+  +sssssssss${c2}hmydMMMMMMMNddddy${c1}ssssssss+
+ /ssssssss${c2}hNMMMyhhyyyyhmNMMMNh${c1}ssssssss/
+.ssssssss${c2}dMMMNh${c1}ssssssssss${c2}hNMMMd${c1}ssssssss.
++ssss${c2}hhhyNMMNy${c1}ssssssssssss${c2}yNMMMy${c1}sssssss+
+oss${c2}yNMMMNyMMh${c1}ssssssssssssss${c2}hmmmh${c1}ssssssso
+oss${c2}yNMMMNyMMh${c1}ssssssssssssss${c2}hmmmh${c1}ssssssso
++ssss${c2}hhhyNMMNy${c1}ssssssssssss${c2}yNMMMy${c1}sssssss+
+.ssssssss${c2}dMMMNh${c1}ssssssssss${c2}hNMMMd${c1}ssssssss.
+ /ssssssss${c2}hNMMMyhhyyyyhdNMMMNh${c1}ssssssss/
+  +sssssssss${c2}dmydMMMMMMMMddddy${c1}ssssssss+
+   /sssssssssss${c2}hdmNNNNmyNMMMMh${c1}ssssss/
+    .ossssssssssssssssss${c2}dMMMNy${c1}sssso.
+      -+sssssssssssssssssyyyssss+-
+        `:+ssssssssssssssssss+:`
+            .-/+oossssoo+/-.
+"#;
+
+pub const FEDORA: &str = r#"
+${c1}             .',;::::;,'.
+         .';:cccccccccccc:;,.
+      .;cccccccccccccccccccccc;.
+    .:cccccccccccccccccccccccccc:.
+  .;ccccccccccccc;.:${c2}dddl${c1}:.;ccccccc;.
+ .:ccccccccccccc;${c2}OWMKOOXMWd${c1};ccccccc:.
+.:ccccccccccccc;${c2}KMM${c1}c;cc;${c2}xMM${c1}c;ccccccc:.
+,cccccccccccccc;${c2}MMM${c1}.;cc;;${c2}WW${c1}:;cccccccc,
+:cccccccccccccc;${c2}MMM${c1}.;cccccccccccccccc:
+:ccccccc;${c2}oxOOOo${c1};${c2}MMM000k${c1}.;cccccccccccc:
+cccccc;${c2}0MMKxdd${c1}:;${c2}MMMkdd${c1}c.;cccccccccccc;
+ccccc;${c2}XMO${c1}';cccc;${c2}MMM${c1}.;cccccccccccccccc'
+ccccc;${c2}MMo${c1};ccccc;${c2}MMW${c1}.;ccccccccccccccc;
+ccccc;${c2}0MN${c1}c.ccc.${c2}xMMd${c1};ccccccccccccccc;
+cccccc;${c2}dNMWXXXWM0${c1}:;cccccccccccccc:,
+cccccccc;.:${c2}odl${c1}:.;cccccccccccccc:,.
+ccccccccccccccccccccccccccccc:'.
+:ccccccccccccccccccccccc:;,..
+ ':cccccccccccccccc::;,.
+"#;
+
+pub const OPENSUSE: &str = r#"
+${c1}           .;ldkO0000Okdl;.
+       .;d00xl:^''''''^:ok00d;.
+     .d00l'                'o00d.
+   .d0Kd'  Okxol:;,.          :O0d.
+  .OKKKK0kOKKKKKKKKKKOxo:,      lKO.
+ ,0KKKKKKKKKKKKKKKK0P^,,,^dx:    ;00,
+.OKKKKKKKKKKKKKKKKk${c2}'.oOPPb.${c1}'0k.   cKO.
+:KKKKKKKKKKKKKKKKK: ${c2}kKx..dd ${c1}lKd   'OK:
+dKKKKKKKKKKKOx0KKKd ${c2}^0KKKO' ${c1}kKKc   dKd
+dKKKKKKKKKKKK;.;oOKx,..^..;kKKK0.  dKd
+:KKKKKKKKKKKK0o;...^cdxxOK0O/^^'  .0K:
+ kKKKKKKKKKKKKKKK0x;,,......,;od  lKk
+ '0KKKKKKKKKKKKKKKKKKKKK00KKOo^  c00'
+  'kKKKOxddxkOO00000Okxoc;''   .dKk'
+    l0Ko.                    .c00l'
+     'l0Kk:.              .;xK0l'
+        'lkK0xl:;,,,,;:ldO0kl'
+            '^:ldxkkkkxdl:^'
+"#;
+
+pub const GENTOO: &str = r#"
+${c1}         -/oyddmdhs+:.
+     -od${c2}NMMMMMMMMNN${c1}mhy+-`
+   -y${c2}NMMMMMMMMMMMNNN${c1}mmdhy+-
+ `om${c2}MMMMMMMMMMMMN${c1}mdmmmmddhhy/`
+ om${c2}MMMMMMMMMMMN${c1}hhyyyohmdddhhhdo`
+.yd${c2}MMMMMMMMMM${c1}dhs++so/smdddhhhhdm+`
+ oyhdm${c2}NMMMMMMMN${c1}dyooydmddddhhhhyh${c2}N${c1}d.
+  :oyhhd${c2}NNMMMMMMMNNN${c1}mmdddhhhhhyym${c2}M${c1}h
+    .:+syd${c2}NMMMMMNNN${c1}mmmdddhhhhhhm${c2}M${c1}my
+       /m${c2}MMMMMMNNN${c1}mmmdddhhhhhm${c2}MN${c1}hs:
+    `o${c2}NMMMMMMMNNN${c1}mmmddddhhdm${c2}MN${c1}hs+`
+  `s${c2}NMMMMMMMMNNN${c1}mmmdddddm${c2}NM${c1}mhs/.
+ /${c2}NMMMMMMMMNNNN${c1}mmmdddm${c2}NMN${c1}dso:`
++${c2}MMMMMMMNNNNN${c1}mmmmdm${c2}NMN${c1}dso/-
+y${c2}MMNNNNNNN${c1}mmmmm${c2}NNM${c1}mhs+/-`
+/h${c2}MMNNNNNNNNMN${c1}dhs++/-`
+`/ohdmmddhys+++/:.`
+  `-//////:--.
+"#;
+
+pub const SLACKWARE: &str = r#"
+${c1}                  :::::::
+            :::::::::::::::::::
+         :::::::::::::::::::::::::
+       ::::::::${c2}cllcccccllllllll${c1}::::::
+    :::::::::${c2}lc               dc${c1}:::::::
+   ::::::::${c2}cl   clllccllll    oc${c1}:::::::::
+  :::::::::${c2}o   lc${c1}::::::::${c2}co   oc${c1}::::::::::
+ ::::::::::${c2}o    cccclc${c1}:::::${c2}clcc${c1}::::::::::::
+ :::::::::::${c2}lc        cclccclc${c1}:::::::::::::
+::::::::::::::${c2}lcclcc          lc${c1}::::::::::::
+::::::::::${c2}cclcc${c1}:::::${c2}lccclc     oc${c1}:::::::::::
+::::::::::${c2}o    l${c1}::::::::::${c2}l    lc${c1}:::::::::::
+ :::::${c2}cll${c1}:${c2}o     clcllcccll     o${c1}:::::::::::
+ :::::${c2}occ${c1}:${c2}o                  clc${c1}:::::::::::
+  ::::${c2}ocl${c1}:${c2}ccslclccclclccclclc${c1}:::::::::::::
+   :::${c2}oclcccccccccccccllllllllllllll${c1}:::::
+    ::${c2}lcclcccccccccccccccccccccccco${c1}::::
+      ::::::::::::::::::::::::::::::::
+        ::::::::::::::::::::::::::::
+           ::::::::::::::::::::::
+                ::::::::::::
+"#;
+
+pub const RHEL: &str = r#"
+${c2}           .${c1}MMM${c2}..:${c1}MMMMMMM
+          MMMMMMMMMMMMMMMMMM
+          MMMMMMMMMMMMMMMMMMMM${c2}.
+${c1}         MMMMMMMMMMMMMMMMMMMMMM
+${c2}        ,${c1}MMMMMMMMMMMMMMMMMMMMMM${c2}:
+${c1}        MMMMMMMMMMMMMMMMMMMMMMMM
+${c2}  .${c1}MMMM${c2}'  ${c1}MMMMMMMMMMMMMMMMMMMMMM
+ MMMMMM    ${c2}`${c1}MMMMMMMMMMMMMMMMMMMM${c2}.
+${c1}MMMMMMMM      MMMMMMMMMMMMMMMMMM ${c2}.
+${c1}MMMMMMMMM${c2}.       `${c1}MMMMMMMMMMMMM${c2}' ${c1}MM${c2}.
+${c1}MMMMMMMMMMM${c2}.                     ${c1}MMMM
+${c2}`${c1}MMMMMMMMMMMMM${c2}.                 ,${c1}MMMMM${c2}.
+ `${c1}MMMMMMMMMMMMMMMMM${c2}.          ,${c1}MMMMMMMM${c2}.
+${c1}    MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM
+      MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM${c2}:
+${c1}         MMMMMMMMMMMMMMMMMMMMMMMMMMMMMM
+${c2}            `${c1}MMMMMMMMMMMMMMMMMMMMMMMM${c2}'
+                `${c1}MMMMMMMMMMMMMMMMM${c2}'
+"#;
+
+// --- Top derivatives ---
+
+pub const MINT: &str = r#"
+${c1} MMMMMMMMMMMMMMMMMMMMMMMMMmds+.
+ MMm----::-://////////////oymNMd+`
+ MMd      ${c2}/++                -sNM${c1}d:
+ MMNso/`  ${c2}dMM    `.::-. .-::.` .h${c1}MN:
+ ddddMMh  ${c2}dMM   :hNMNMNhNMNMNh: `${c1}NMm
+     NMm  ${c2}dMM  .NMN/-+MMM+-/NMN` ${c1}dMM
+     NMm  ${c2}dMM  -MMm   `MMM   dMM. ${c1}dMM
+     NMm  ${c2}dMM  -MMm   `MMM   dMM. ${c1}dMM
+     NMm  ${c2}dMM  .mmd   `mmm   yMM. ${c1}dMM
+     NMm  ${c2}dMM`  ..`   ...    ydm. ${c1}dMM
+     hMM- ${c2}+MMd/-------...-:sdds  ${c1}dMM
+     -NMm${c2}- :hNMNNNmdddddddddy/`  ${c1}dMM
+      -dM${c2}Ns-``-::::-------.``    ${c1}dMM
+       `/dMNmy+/:-------------:/yMMM
+          ./ydNMMMMMMMMMMMMMMMMMMMMM
+             .MMMMMMMMMMMMMMMMMMM
+"#;
+
+pub const MANJARO: &str = r#"
+${c1}██████████████████  ████████
+██████████████████  ████████
+██████████████████  ████████
+██████████████████  ████████
+████████            ████████
+████████  ████████  ████████
+████████  ████████  ████████
+████████  ████████  ████████
+████████  ████████  ████████
+████████  ████████  ████████
+████████  ████████  ████████
+████████  ████████  ████████
+████████  ████████  ████████
+████████  ████████  ████████
+"#;
+
+pub const ENDEAVOUROS: &str = r#"
+${c1}                     ./${c2}o${c3}.
+${c1}                   ./${c2}sssso${c3}-
+${c1}                 `:${c2}osssssss+${c3}-
+${c1}               `:+${c2}sssssssssso${c3}/.
+${c1}             `-/o${c2}ssssssssssssso${c3}/.
+${c1}           `-/+${c2}sssssssssssssssso${c3}+:`
+${c1}         `-:/+${c2}sssssssssssssssssso${c3}+/.
+${c1}       `.://o${c2}sssssssssssssssssssso${c3}++-
+${c1}      .://+${c2}ssssssssssssssssssssssso${c3}++:
+${c1}    .:///o${c2}ssssssssssssssssssssssssso${c3}++:
+${c1}  `:////${c2}ssssssssssssssssssssssssssso${c3}+++.
+${c1}`-////+${c2}ssssssssssssssssssssssssssso${c3}++++-
+${c1} `..-+${c2}oosssssssssssssssssssssssso${c3}+++++/`
+${c3}   ./++++++++++++++++++++++++++++++/:.
+  `:::::::::::::::::::::::::------``
+"#;
+
+pub const POP_OS: &str = r#"
+${c1}             /////////////
+         /////////////////////
+      ///////${c2}767${c1}////////////////
+    //////${c2}7676767676*${c1}//////////////
+   /////${c2}76767${c1}//${c2}7676767${c1}//////////////
+  /////${c2}767676${c1}///${c2}*76767${c1}///////////////
+ ///////${c2}767676${c1}///${c2}76767.${c1}///${c2}7676*${c1}///////
+/////////${c2}767676${c1}//${c2}76767${c1}///${c2}767676${c1}////////
+//////////${c2}76767676767${c1}////${c2}76767${c1}/////////
+///////////${c2}76767676${c1}//////${c2}7676${c1}//////////
+////////////${c2},7676,${c1}///////${c2}767${c1}///////////
+/////////////${c2}*7676${c1}///////${c2}76${c1}////////////
+///////////////${c2}7676${c1}////////////////////
+ ///////////////${c2}7676${c1}///${c2}767${c1}////////////
+  //////////////////////${c2}'${c1}////////////
+   //////${c2}.7676767676767676767,${c1}//////
+    /////${c2}767676767676767676767${c1}/////
+      ///////////////////////////
+         /////////////////////
+             /////////////
+"#;
+
+pub const MX_LINUX: &str = r#"
+${c1}MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM
+MMMMMMMMMNs..yMMMMMMMMMMMMMm: +NMMMMMMMMMMMMM
+MMMMMMMMMMMNo  /MMMMMMMMMMMN-  hMMMMMMMMMMMMM
+MMMMMMMMMMMMMm  `sMMMMMMMMMy `oMMMMMMMMMMMMMM
+MMMMMMMMMMMMMMM+  -NMMMMMMo  /MMMMMMMMMMMMMMM
+MMMMMMMMMMMMMMMMy  .mMMMN-  yMMMMMMMMMMMMMMMM
+MMMMMMMMMMMMMMMMMN-  /MM+  :NMMMMMMMMMMMMMMMM
+MMMMMMMMMMMMMMMMMMMo  hd  .mMMMMMMMMMMMMMMMMM
+MMMMMMMMMMMMMMMMMMN:  .-  +MMMMMMMMMMMMMMMMMM
+MMMMMMMMMMMMMMMMMy   :o.   hMMMMMMMMMMMMMMMMM
+MMMMMMMMMMMMMMMm.  `yMMNo   +MMMMMMMMMMMMMMMM
+MMMMMMMMMMMMMMd.  /NMMMMMd:  -hMMMMMMMMMMMMMM
+${c2}MMMMMMMMMMMMM${c1}s   -o:s${c2}MMMMMM${c1}h.  .N${c2}MMMMMMMMMMMM
+MMMMMMMMMMM${c1}N/   :.   .y${c2}MMMMMM${c1}No  :m${c2}MMMMMMMMMM
+MMMMMMMMMM${c1}d.  ./+o.    :m${c2}MMMMMMM${c1}s`  s${c2}MMMMMMMM
+MMMMMMMMM${c1}s.  .h${c2}MMMMM${c1}d+.  .h${c2}MMMMMMM${c1}N/ `h${c2}MMMMMM
+MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM
+"#;
+
+pub const ZORIN: &str = r#"
+${c1}        `osssssssssssssssssssso`
+       .osssssssssssssssssssssso.
+      .+oooooooooooooooooooooooo+.
+
+
+${c2}  `::::::::::::::::::::::.         .:`
+ `+ssssssssssssssssss+:.`     `.:+ssso`
+.ossssssssssssssso/.       `-+ossssssso.
+ssssssssssssso/-`      `-/osssssssssssss
+.ossssssso/-`      .-/ossssssssssssssso.
+ `+sss+:.      `.:+ssssssssssssssssss+`
+${c1}  `:.         .::::::::::::::::::::::`
+
+
+      .+oooooooooooooooooooooooo+.
+       -osssssssssssssssssssssso-
+        `osssssssssssssssssssso`
+"#;
+
+pub const ELEMENTARY: &str = r#"
+${c1}         eeeeeeeeeeeeeeeee
+      eeeeeeeeeeeeeeeeeeeeeee
+    eeeee  eeeeeeeeeeee   eeeee
+  eeee   ${c2}eeeee       eee     ${c1}eeee
+ eeee   ${c2}eeee          eee     ${c1}eeee
+eee    ${c2}eee            eee       ${c1}eee
+eee   ${c2}eee            eee        ${c1}eee
+ee    ${c2}eee           eeee       ${c1}eeee
+ee    ${c2}eee         eeeee      ${c1}eeeeee
+ee    ${c2}eee       eeeee      ee${c1}eee ee
+eee   ${c2}eeee   eeeeee      eeee${c1}e  eee
+eee    ${c2}eeeeeeeeee     eeeeee    ${c1}eee
+ eeeee${c2}eeeeeeeeeeeeeeeeeee    ${c1}eeeee
+  eeee${c2}eeee eeeeeeeeeeee      ${c1}eeee
+    eeeee                 eeeee
+      eeeeeee         eeeeeee
+         eeeeeeeeeeeeeeeee
+"#;
+
+pub const KALI: &str = r#"
+${c1}..............
+            ..,;:ccc,.
+          ......''';lxO.
+.....''''..........,:ld;
+           .';;;:::;,,.x,
+      ..'''.            0Xxoc:,.  ...
+  ....                ,ONkc;,;cokOdc',.
+ .                   OMo           ':ddo.
+                    dMc               :OO;
+                    0M.                 .:o.
+                    ;Wd
+                     ;XO,
+${c2}                       ,d0Odlc;,..
+                           ..',;:cdOOd::,.
+                                    .:d;.':;.
+                                       'd,  .'
+                                         ;l   ..
+                                          .o
+                                            c
+                                            .'
+                                             .
+"#;
+
+pub const PARROT: &str = r#"
+${c1}  `:oho/-`
+`m${c2}MMMMMMMMMMMN${c1}mmdhy-
+ d${c2}MMMMMMMMMMMMMMMMMM${c1}s`
+ +${c2}MM${c1}soh${c2}NMMMMMMMMMMMMM${c1}m/
+ .${c2}M${c1}y   .+d${c2}MMMMMMMMMMMMM${c1}h.
+  +       :${c2}NMMMMMMMMMMMMN${c1}o
+           `y${c2}MMMMMMMMMMMMM${c1}m:
+             /${c2}NMMMMMMMMMMMMM${c1}y`
+              .h${c2}MMMMMMMMMMMMMN${c1}+
+                  ``-${c2}NMMMMMMMMM${c1}d-
+                     /${c2}MMMMMMMMMMM${c1}s`
+                      m${c2}MMMMMMM${c1}sy${c2}NMN${c1}/
+                      +${c2}MMMMMMM${c1}o  :s${c2}N${c1}h.
+                      `${c2}NMMMMMM${c1}m     -o/
+                       o${c2}MMMMMMM${c1}.
+                       `${c2}NMMMMMM${c1}+
+                        +${c2}MM${c1}d/${c2}NM${c1}h
+                         m${c2}M${c1}m -m${c2}N${c1}`
+                         /${c2}MM  ${c1}`h:
+                          d${c2}M${c1}`   .
+                          :${c2}M${c1}-
+                           d:
+                            -+
+                             -
+"#;
+
+pub const GARUDA: &str = r#"
+${c1}                     .%;888:8898898:
+                   x;XxXB%89b8:b8%b88:
+                .8Xxd                8X:.
+              .8Xx;                    8x:.
+            .tt8x          .d            x88;
+         .@8x8;          .db:              xx@;
+       ,tSXX°          ${c2}.bbbbbbbbbbbbbbbbbbbB8x@;
+${c1}     .SXxx            ${c2}bBBBBBBBBBBBBBBBBBBbSBX8;
+${c1}   ,888S                                     pd!
+  8X88/                                       q
+  GBB.
+   x%88        d888@8@X@X@X88X@@XX@@X@8@X.
+     dxXd    dB8b8b8B8B08bB88b998888b88x.
+      dxx8o                      .@@;.
+        dx88                   .t@x.
+          d:SS@8ba89aa67a853Sxxad.
+            .d988999889889899dd.
+"#;
+
+pub const NOBARA: &str = r#"
+${c1} ███████████████████         █████████
+ ████████████████████████    █████████
+ ███████████████████████████ █████████
+ █████████           ████████████████
+ █████████   ${c2}█████     ${c1}███████████████
+ █████████  ${c2}███████     ${c1}██████████████
+ █████████  ${c2}███████      ${c1}█████████████
+ █████████   ${c2}█████        ${c1}████████████
+ █████████                 ███████████
+ █████████   ███████████    ██████████
+ █████████   ███████████     █████████
+ █████████   ███████████     █████████
+ █████████                   █████████
+"#;
+
+pub const CACHYOS: &str = r#"
+${c1}           .-----------------------${c2}--:
+${c1}          .+=======================${c2}==.
+${c1}         :++===++==================${c2}-       :++-
+${c1}        :*++====+++++=============-        ${c2}.==:
+${c1}       -*+++=====+***++==========:
+      =*++++========------------:
+     =*+++++=====-                     ${c2}...
+${c1}   .+*+++++=-===:                    ${c2}.=+++=:
+${c1}  :++++=====-==:                     ${c2}-*****+
+${c1} :++========-=.                      ${c2}.=+**+.
+${c1}.+==========-.                          ${c2}.
+${c1} :+++++++====-                                ${c2}.--==-.
+${c1}  :++==========.                             ${c2}:+++++++:
+${c1}   .-===========.                            ${c2}=*****+*+
+${c1}    .-===========:                           ${c2}.+*****+:
+${c1}      -=======++++:::::::::::::::::::::::::-:  .---:
+       :======++++====+++******************=.
+        :=====+++==========++++++++++++++*-
+         .====++==============++++++++++*-
+          .===+==================+++++++:
+           .-=======================+++:
+             ..........................
+"#;
+
+// --- Server / Enterprise ---
+
+pub const ALMALINUX: &str = r#"
+${c1}         'c:.
+${c1}        lkkkx, ..       ${c2}..   ,cc,
+${c1}        okkkk:ckkx'  ${c2}.lxkkx.okkkkd
+${c1}        .:llcokkx'  ${c2}:kkkxkko:xkkd,
+${c1}      .xkkkkdood:  ${c2};kx,  .lkxlll;
+${c1}       xkkx.       ${c2}xk'     xkkkkk:
+${c1}       'xkx.       ${c2}xd      .....,.
+${c3}      .. ${c1}:xkl'     ${c2}:c      ..''..
+${c3}    .dkx'  ${c1}.:ldl:'. ${c2}'  ${c4}':lollldkkxo;
+${c3}  .''lkkko'                     ${c4}ckkkx.
+${c3}'xkkkd:kkd.       ..  ${c3};'        ${c4}:kkxo.
+${c3},xkkkd;kk'      ,d;    ${c3}ld.   ${c4}':dkd::cc,
+${c3} .,,.;xkko'.';lxo.      ${c3}dx,  ${c4}:kkk'xkkkkc
+${c3}     'dkkkkkxo:.        ${c3};kx  ${c4}.kkk:;xkkd.
+${c3}       .....   ${c3}.;dk:.   ${c3}lkk.  ${c4}:;,
+${c3}             :kkkkkkkdoxkkx
+              ,c,,;;;:xkkd.
+                ;kkkkl...
+                ;kkkkl
+                 ,od;
+"#;
+
+pub const ROCKY: &str = r#"
+${c1}          __wgliliiligw_,
+       _williiiiiiliilililw,
+     _%iiiiiilililiiiiiiiiiii_
+   .Qliiiilililiiiiiiililililiw.
+  _iiiiiililiiiiiiiiiiiiiiiiiiii_
+ .lliiiiiiiiiiililililiiiiiiiilil.
+ iiiiiiliiiiii${c2}'`"*${c1}iiiiiiiiiiiiiiii
+.iiiiiiiiiiiI${c2}' `"*${c1}iiiiiiiiiiiiiii.
+lliiiiiiiil${c2}P'     `"*${c1}iiiiiiiiiiiii
+iiiiiiiii${c2}P'          `*${c1}iiiiiiiiiii
+${c2}*${c1}iiiiii${c2}P'   ${c1}_,w_    ${c2}`"*${c1}iiiiiiiii
+ llll${c2}P'   ${c1}_wlllllw_    ${c2}`"*${c1}lllll${c2}'
+  *P'  ${c1}_wlllllllllllw,    ${c2}`*P'
+${c1}   _.wlllllllllllllllllw,_  '
+    *llllllllllllllllllll*
+      *llllllllllllllll*
+         "*llllllllll*"
+"#;
+
+pub const CENTOS: &str = r#"
+${c1}                 ..
+               .PLTJ.
+              <><><><>
+     ${c2}KKSSV' 4KKK ${c1}LJ${c4} KKKL.'VSSKK
+     ${c2}KKV' 4KKKKK ${c1}LJ${c4} KKKKAL 'VKK
+     ${c2}V' ' 'VKKKK ${c1}LJ${c4} KKKKV' ' 'V
+     ${c2}.4MA.' 'VKK ${c1}LJ${c4} KKV' '.4Mb.
+${c4}   . ${c2}KKKKKA.' 'V ${c1}LJ${c4} V' '.4KKKKK ${c3}.
+${c4} .4D ${c2}KKKKKKKA.'' ${c1}LJ${c4} ''.4KKKKKKK ${c3}FA.
+${c4}<QDD ++++++++++++  ${c3}++++++++++++ GFD>
+${c4} 'VD ${c3}KKKKKKKK'.. ${c2}LJ ${c1}..'KKKKKKKK ${c3}FV
+${c4}   ' ${c3}VKKKKK'. .4 ${c2}LJ ${c1}K. .'KKKKKV ${c3}'
+     ${c3} 'VK'. .4KK ${c2}LJ ${c1}KKA. .'KV'
+     ${c3}A. . .4KKKK ${c2}LJ ${c1}KKKKA. . .4
+     ${c3}KKA. 'KKKKK ${c2}LJ ${c1}KKKKK' .4KK
+     ${c3}KKSSA. VKKK ${c2}LJ ${c1}KKKV .4SSKK
+${c2}              <><><><>
+               'MKKM'
+                 ''
+"#;
+
+pub const ALPINE: &str = r#"
+${c1}       .hddddddddddddddddddddddh.
+      :dddddddddddddddddddddddddd:
+     /dddddddddddddddddddddddddddd/
+    +dddddddddddddddddddddddddddddd+
+  `sdddddddddddddddddddddddddddddddds`
+ `ydddddddddddd${c2}++${c1}hdddddddddddddddddddy`
+.hddddddddddd${c2}+`  `+${c1}ddddh${c2}:-s${c1}dddddddddddh.
+hdddddddddd${c2}+`      `+${c1}y${c2}:    .s${c1}ddddddddddh
+ddddddddh${c2}+`   `//`   `.`     -s${c1}ddddddddd
+ddddddh${c2}+`   `/${c1}hddh${c2}/`   `:s-    -s${c1}ddddddd
+ddddh${c2}+`   `/+/${c1}dddddh${c2}/`   `+s-    -s${c1}ddddd
+ddd${c2}+`   `/o` :${c1}dddddddh${c2}/`   `o${c1}y${c2}-    .${c1}yddd
+hdddyo+ohddyosdddddddddho+oydddy++ohdddh
+.hddddddddddddddddddddddddddddddddddddh.
+ `yddddddddddddddddddddddddddddddddddy`
+  `sdddddddddddddddddddddddddddddddds`
+    +dddddddddddddddddddddddddddddd+
+     /dddddddddddddddddddddddddddd/
+      :dddddddddddddddddddddddddd:
+       .hddddddddddddddddddddddh.
+"#;
+
+pub const ORACLE_LINUX: &str = r#"
+${c1}      `-/+++++++++++++++++/-.`
+   `/syyyyyyyyyyyyyyyyyyyyyyys/.
+  :yyyyo/-...............-/oyyyy/
+ /yyys-                     .oyyy+
+.yyyy`                       `syyy-
+:yyyo      ${c2}O R A C L E        ${c1}/yyy/
+.yyyy`                       `syyy-
+ /yyys.                     .oyyyo
+  /yyyyo:-...............-:oyyyy/`
+   `/syyyyyyyyyyyyyyyyyyyyyyys+.
+     `.:/+ooooooooooooooo+/:.`
+"#;
+
+// --- Others ---
+
+pub const NIXOS: &str = r#"
+${c1}          ::::.    ${c2}':::::     ::::'
+${c1}          ':::::    ${c2}':::::.  ::::'
+${c1}            :::::     ${c2}'::::.:::::
+${c1}      .......:::::..... ${c2}::::::::
+${c1}     ::::::::::::::::::. ${c2}::::::    ${c1}::::.
+     ::::::::::::::::::::: ${c2}:::::.  ${c1}.::::'
+${c2}            .....           ::::' ${c1}:::::'
+${c2}           :::::            '::' ${c1}:::::'
+${c2}  ........:::::               ' ${c1}:::::::::::.
+${c2} :::::::::::::                 ${c1}:::::::::::::
+${c2}  ::::::::::: ${c1}..              ${c1}:::::
+${c2}      .::::: ${c1}.:::            ${c1}:::::
+${c2}     .:::::  ${c1}:::::          ${c1}'''''    ${c2}.....
+     :::::   ${c1}':::::.  ${c2}......:::::::::::::'
+      :::     ${c1}::::::. ${c2}':::::::::::::::::'
+${c1}            .:::::::: ${c2}'::::::::::
+${c1}           .::::''::::.     ${c2}'::::.
+${c1}          .::::'   ::::.     ${c2}'::::.
+${c1}         .::::      ::::      ${c2}'::::.
+"#;
+
+pub const VOID: &str = r#"
+${c1}                __.;=====;.__
+            _.=+==++=++=+=+===;.
+             -=+++=+===+=+=+++++=_
+        .     -=:``     `--==+=++==.
+       _vi,    `            --+=++++:
+      .uvnvi.       _._       -==+==+.
+     .vvnvnI`    .;==|==;.     :|=||=|.
++QmQQmpvvnv; ${c2}_yYsyQQWUUQQQm #QmQ#:QQQWUV$QQm.
+${c1} -QQWQWpvvow${c2}Z?.wQQQE==<QWWQ/QWQW.QQWW(: jQWQE
+${c1}  -$QQQQmmU'  ${c2}jQQQ@+=<QWQQ)mQQQ.mQQQC+;jWQQ@'
+${c1}   -$WQ8YnI:   ${c2}QWQQwgQQWV`mWQQ.jQWQQgyyWW@!
+${c1}     -1vvnvv.     `~+++`        ++|+++
+      +vnvnnv,                 `-|===
+       +vnvnvns.           .      :=-
+        -Invnvvnsi..___..=sv=.     `
+          +Invnvnvnnnnnnnnvvnn;.
+            ~|Invnvnvvnvvvnnv}+`
+               -~"|{*l}*|""~
+"#;
+
+pub const SOLUS: &str = r#"
+${c1}            -```````````
+          `-+/------------.`
+       .---:${c2}mNo${c1}---------------.
+     .-----${c2}yMMMy${c1}:---------------.
+   `------${c2}oMMMMMm${c1}/----------------`
+  .------/${c2}MMMMMMMN${c1}+----------------.
+ .------/${c2}NMMMMMMMMm${c1}-+/--------------.
+`------/${c2}NMMMMMMMMMN${c1}-:${c2}mh${c1}/-------------`
+.-----/${c2}NMMMMMMMMMMM${c1}:-+${c2}MMd${c1}//${c2}oso${c1}/:-----.
+-----/${c2}NMMMMMMMMMMMM${c1}+--${c2}mMMMh${c1}::${c2}smMmyo${c1}:-.
+----+${c2}NMMMMMMMMMMMMMo${c1}--${c2}yMMMMNo${c1}-:${c2}yMMMMd${c1}/.
+.--${c2}oMMMMMMMMMMMMMMMy${c1}--${c2}yMMMMMMh${c1}:-${c2}yMMMy${c1}-`
+`-${c2}sMMMMMMMMMMMMMMMMh${c1}--${c2}dMMMMMMMd${c1}:/${c2}Ny${c1}+${c2}y${c1}.
+`-/+${c2}osyhhdmmNNMMMMMm${c1}-/${c2}MMMMMMMmh${c1}+/${c2}ohm${c1}+
+  .------------:://+-/++++++${c2}oshddys${c1}:
+   -${c2}hhhhyyyyyso${c1}++/:---------:/+++/-
+    `/${c2}ydmmmmmmmmmmmmmmmmmmmdhs${c1}/`
+      `.`
+"#;
+
+pub const PUPPY: &str = r#"
+${c1}           `-/osyyyysosyhhhhhyys+/-
+        -ohmNNmh+/hMMMMMMMMNNNNd+dMMMMNM+
+      yMMMMNNmmddo/NMMMNNNNNNNNNo+NNNNNy
+    .NNNNNNmmmddds:MMNNNNNNNNNNNh:mNNN/
+    -NNNdyyyhdmmmd`dNNNNNmmmmNNmdd/os/
+    .Nm+shddyooo+/smNNNNmmmmNh.   :mmd.
+     NNN${c2}Ny:`   ./hmmmmmmm${c1}NNNN:     hNMh
+     NMN${c2}-    -++- +NNNNNN${c1}NNNNm+..-sMMMM-
+    .MMo    ${c2}oNNNNo hNNNNN${c1}NNNmhdNNNMMMMM+
+    .MMs    ${c2}/NNNN/ dNmhs+${c1}:-`  yMMMMMMMM+
+     mMM${c2}+     .. `sNN+.      ${c1}hMMMMhhMMM-
+     -MMMMh:  o:hMMM+        `MMM  NMMN.
+      .+dMMMMMN/dMMMMN      `/dMMMdNMMh-
+       `.oMMMMMM`dMMMMN`     .MMMMMMMMm.
+         `-hMMN+ dMMMMd     `MMMMMMMMM-
+           `:sdNmmmdhyo+:---:+shNMMMm:
+               `.-oMMN` `./hyshMMMMN:
+                   `:ooo.:NMMMMMNo.
+                       `-::::-.
+"#;
+
+pub const FREEBSD: &str = r#"
+${c2}```                        ${c1}`
+${c2}  ` `.${c1}....---.......--.```   -/
+${c2}  +o   ${c1}.--`         /y:`      +.
+${c2}   yo`${c1}:.            :o      `+-
+    y/               -/`   -o/
+   .-                  ::/sy+:.
+   /                     `--  /
+  `:                          :`
+  `:                          :`
+   /                          /
+   .-                        -.
+    --                      -.
+     `:`                  `:`
+       .--             `--.
+          .---.....----.
+"#;
+
+pub const RASPBIAN: &str = r#"
+${c2}   `.::///+:/-.        --///+//-:``
+ `+oooooooooooo:   `+oooooooooooo:
+  /oooo++//ooooo:  ooooo+//+ooooo.
+  `+ooooooo:-:oo-  +o+::/ooooooo:
+   `:oooooooo+``    `.oooooooo+-
+     `:++ooo/.        :+ooo+/.`
+${c1}        ...`  `.----.` ``..
+     .::::-``:::::::::.`-:::-`
+    -:::-`   .:::::::-`  `-:::-
+   `::.  `.--.`  `` `.---.``.::`
+       .::::::::`  -::::::::` `
+ .::` .:::::::::- `::::::::::``::.
+-:::` ::::::::::.  ::::::::::.`:::-
+::::  -::::::::.   `-::::::::  ::::
+-::-   .-:::-.``....``.-::-.   -::-
+ .. ``       .::::::::.     `..`..
+   -:::-`   -::::::::::`  .:::::`
+   :::::::` -::::::::::` :::::::.
+   .:::::::  -::::::::. ::::::::
+    `-:::::`   ..--.`   ::::::.
+      `...`  `...--..`  `...`
+            .::::::::::
+             `.-::::-`
+"#;
+
+// --- Windows / macOS ---
+
+pub const WINDOWS10: &str = r#"
+${c1}                                ..,
+                    ....,,:;+ccllll
+      ...,,+:;  cllllllllllllllllll
+,cclllllllllll  lllllllllllllllllll
+llllllllllllll  lllllllllllllllllll
+llllllllllllll  lllllllllllllllllll
+llllllllllllll  lllllllllllllllllll
+llllllllllllll  lllllllllllllllllll
+llllllllllllll  lllllllllllllllllll
+
+${c2}llllllllllllll  lllllllllllllllllll
+llllllllllllll  lllllllllllllllllll
+llllllllllllll  lllllllllllllllllll
+llllllllllllll  lllllllllllllllllll
+llllllllllllll  lllllllllllllllllll
+`'ccllllllllll  lllllllllllllllllll
+       `' \*::  :ccllllllllllllllll
+                       ````''*::cll
+                                 ``
+"#;
+
+pub const WINDOWS11: &str = r#"
+${c1}################  ################
+################  ################
+################  ################
+################  ################
+################  ################
+################  ################
+################  ################
+
+${c2}################  ################
+################  ################
+################  ################
+################  ################
+################  ################
+################  ################
+################  ################
+"#;
+
+pub const MACOS: &str = r#"
+${c1}                    c.'
+                 ,xNMM.
+               .OMMMMo
+               lMM"
+     .;loddo:.  .olloddol;.
+   cKMMMMMMMMMMNWMMMMMMMMMM0:
+${c2} .KMMMMMMMMMMMMMMMMMMMMMMMWd.
+ XMMMMMMMMMMMMMMMMMMMMMMMX.
+${c3};MMMMMMMMMMMMMMMMMMMMMMMM:
+:MMMMMMMMMMMMMMMMMMMMMMMM:
+${c4}.MMMMMMMMMMMMMMMMMMMMMMMMX.
+ kMMMMMMMMMMMMMMMMMMMMMMMMWd.
+${c5} 'XMMMMMMMMMMMMMMMMMMMMMMMMMMk
+  'XMMMMMMMMMMMMMMMMMMMMMMMMK.
+${c6}    kMMMMMMMMMMMMMMMMMMMMMMd
+     ;KMMMMMMMWXXWMMMMMMMk.
+       "cooc*"    "*coo'"
+"#;
+
+// --- Fallback (Tux) ---
+
+pub const FALLBACK: &str = r#"
+${c1}        a8888b.
+       d888888b.
+       8P"YP"Y88
+       8|o||o|88
+       8'    .88
+       8${c2}`._.' ${c1}Y8.
+      d/      `8b.
+     dP   .    Y8b.
+    d8:'  "  `::88b
+   d8"         'Y88b
+  :8P    '      :888
+   8a.   :     _a88P
+${c2} ._/"Yaa_${c1}:   ${c2}.| ${c1}88${c2}P|
+ \    YP"    `| ${c1}8${c2}P  `.
+ /     \.${c1}___.d${c2}|    .'
+ `--..__)${c1}8888${c2}P`._.'
+"#;
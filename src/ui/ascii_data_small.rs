@@ -0,0 +1,354 @@
+//! Small ASCII art variants (about 6–9 lines) for short info blocks and narrow terminals.
+//! Same names as in ascii_data.rs; colors come from the same palettes in `logos`.
+
+// --- Major families ---
+
+pub const ARCH: &str = r#"
+${c1}      /\
+     /  \
+    /\   \
+${c2}   /      \
+  /   ,,   \
+ /   |  |  -\
+/_-''    ''-_\
+"#;
+
+pub const DEBIAN: &str = r#"
+${c1}  _____
+ /  __ \
+|  /    |
+|  \___-
+-_
+  --_
+"#;
+
+pub const UBUNTU: &str = r#"
+${c1}         _
+     ---(_)
+ _/  ---  \
+(_) |   |
+  \  --- _/
+     ---(_)
+"#;
+
+pub const FEDORA: &str = r#"
+${c2}      _____
+     /   __)${c1}\
+     |  /  ${c1}\ \
+${c1}  __${c2}_|  |_${c1}_/ /
+${c1} / ${c2}(_    _)${c1}_/
+${c1}/ /  ${c2}|  |
+${c1}\ \${c2}__/  |
+ ${c1}\${c2}(_____/
+"#;
+
+pub const OPENSUSE: &str = r#"
+${c1}  _______
+__|   __ \
+     / .\ \
+     \__/ |
+   _______|
+   \_______
+__________/
+"#;
+
+pub const GENTOO: &str = r#"
+${c1} _-----_
+(       \
+\    0   \
+${c2} \        )
+ /      _/
+(     _-
+\____-
+"#;
+
+pub const SLACKWARE: &str = r#"
+${c1}   ________
+  /  ______|
+  | |______
+  \______  \
+   ______| |
+| |________/
+|____________
+"#;
+
+pub const RHEL: &str = r#"
+${c1}      .M.:MMM
+     MMMMMMMMMM.
+    ,MMMMMMMMMMM
+ .MM MMMMMMMMMMM
+MMMM   MMMMMMMMM
+MMMMMM           MM
+ MMMMMMMMM     ,MMMM
+   MMMMMMMMMMMMMMMM:
+      `MMMMMMMMMMMM
+"#;
+
+// --- Top derivatives ---
+
+pub const MINT: &str = r#"
+${c1} ___________
+|_          \
+  | ${c2}| _____ ${c1}|
+  | ${c2}| | | | ${c1}|
+  | ${c2}| | | | ${c1}|
+  | ${c2}\_____/ ${c1}|
+  \_________/
+"#;
+
+pub const MANJARO: &str = r#"
+${c1}||||||||| ||||
+||||||||| ||||
+||||      ||||
+|||| |||| ||||
+|||| |||| ||||
+|||| |||| ||||
+|||| |||| ||||
+"#;
+
+pub const ENDEAVOUROS: &str = r#"
+${c1}          /${c2}o${c3}.
+${c1}        /${c2}sssso${c3}-
+${c1}      /${c2}ossssssso${c3}:
+${c1}    /${c2}ssssssssssso${c3}+
+${c1}  /${c2}ssssssssssssssso${c3}+
+${c1}//${c2}osssssssssssssso${c3}+-
+ ${c3}`+++++++++++++++-`
+"#;
+
+pub const POP_OS: &str = r#"
+${c1}______
+\   _ \        __
+ \ \ \ \      / /
+  \ \_\ \    / /
+   \  ___\  /_/
+    \ \    _
+   __\_\__(_)_
+  (___________)
+"#;
+
+pub const MX_LINUX: &str = r#"
+${c1}    \\  /
+     \\/
+      \\
+   /\/ \\
+  /  \  /\
+ /    \/  \
+/__________\
+"#;
+
+pub const ZORIN: &str = r#"
+${c1}  _________
+ /_________\
+   _______
+${c2}  /  ___  \
+ /__/   \__\
+ _________
+ \_________/
+"#;
+
+pub const ELEMENTARY: &str = r#"
+${c1}  _______
+ / ____  \
+/  |  /  /\
+|__\ /  / |
+\   /__/  /
+ \_______/
+"#;
+
+pub const KALI: &str = r#"
+${c1}  ____
+ /    \_____
+|  ${c2}(o)${c1}       \
+ \____        \
+      \____    \
+           \    )
+            \  /
+             \/
+"#;
+
+pub const PARROT: &str = r#"
+${c1}  ____
+ /    \___
+|  ${c2}o${c1}     \__
+ \__        \
+    \__  ${c2}___${c1}/
+       \/
+"#;
+
+pub const GARUDA: &str = r#"
+${c1}     .----.
+   .'   ,  '.
+ .'    '-----|
+'.   -----,
+  '.____.'
+"#;
+
+pub const NOBARA: &str = r#"
+${c1} _____  ____
+|     \|    |
+|  |\  \    |
+|  | \  \   |
+|  |  \  \  |
+|__|   \____|
+"#;
+
+pub const CACHYOS: &str = r#"
+${c1}   /''''''''''''/
+  /''''''''''''/
+ /''''''/
+/''''''/
+\......\
+ \......\
+  \.............../
+   \............./
+"#;
+
+// --- Server / Enterprise ---
+
+pub const ALMALINUX: &str = r#"
+${c1}   _${c3}    _
+${c1} ('-')${c3}  (-)
+${c1}  \_\${c3}  / /
+${c2}   __  ${c4}__
+${c2}  (  )${c4}(  )
+${c2}   ''  ${c4}''
+"#;
+
+pub const ROCKY: &str = r#"
+${c1}    `-/+++++++++/-.`
+ `-+++++++++++++++++-`
+.+++++++++++++++++++++.
+-+++++++++++++/-:++++++
+-+++++++/-` `/++.`:+++-
+ .+++++:  .+++++:  .++.
+   `-+/-+++++++/-.-`
+"#;
+
+pub const CENTOS: &str = r#"
+${c2} ____${c1}^${c4}____
+${c2} |\  ${c1}|${c4}  /|
+${c2} | \ ${c1}|${c4} / |
+${c4}<---- ${c3}---->
+${c3} | / ${c2}|${c1} \ |
+${c3} |/__${c2}|${c1}__\|
+${c2}     v
+"#;
+
+pub const ALPINE: &str = r#"
+${c1}   /\ /\
+  // \  \
+ //   \  \
+///    \  \
+//      \  \
+         \
+"#;
+
+pub const ORACLE_LINUX: &str = r#"
+${c1}  _________________
+ /                 \
+|                   |
+|                   |
+ \_________________/
+"#;
+
+// --- Others ---
+
+pub const NIXOS: &str = r#"
+${c1}  \\  \\ //
+ ==\\__\\/ //
+   //   \\//
+==//     //==
+ //\\___//
+// /\\  \\==
+  // \\  \\
+"#;
+
+pub const VOID: &str = r#"
+${c1}    _______
+ _ \______ -
+| \  ___  \ |
+| | /   \ | |
+| | \___/ | |
+| \______ \_|
+ -_______\
+"#;
+
+pub const SOLUS: &str = r#"
+${c1}     /|
+    / |\
+   /  | \ _
+  /___|__\_\
+${c2} \         /
+  `-------´
+"#;
+
+pub const PUPPY: &str = r#"
+${c1}   __     __
+  (  \___/  )
+   \ o   o /
+    \  v  /
+  ___\___/___
+ (___________)
+"#;
+
+pub const FREEBSD: &str = r#"
+${c1}/\,-'''''-,/\
+\_)       (_/
+|           |
+|           |
+ ;         ;
+  '-_____-'
+"#;
+
+pub const RASPBIAN: &str = r#"
+${c2}   .~~.   .~~.
+  '. \ ' ' / .'
+${c1}   .~ .~~~..~.
+  : .~.'~'.~. :
+ ~ (   ) (   ) ~
+( : '~'.~.'~' : )
+ ~ .~       ~. ~
+  '~'       '~'
+"#;
+
+// --- Windows / macOS ---
+
+pub const WINDOWS10: &str = r#"
+${c1} ________ _______
+|        |       |
+|        |       |
+|--------+-------|
+|        |       |
+|________|_______|
+"#;
+
+pub const WINDOWS11: &str = r#"
+${c1}########  ########
+########  ########
+########  ########
+
+########  ########
+########  ########
+########  ########
+"#;
+
+pub const MACOS: &str = r#"
+${c1}        .:'
+    __ :'__
+${c2} .'`  `-'  ``.
+${c3}:          .-'
+${c4}:         :
+${c5} :         `-;
+${c6}  `.__.-.__.'
+"#;
+
+pub const FALLBACK: &str = r#"
+${c1}    ___
+   (.. |
+   (${c2}<>${c1} |
+  / __  \
+ ( /  \ /|
+${c2}_${c1}/\ __)/${c2}_${c1})
+${c2}\/${c1}-____${c2}\/
+"#;
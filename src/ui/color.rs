@@ -0,0 +1,143 @@
+//! ANSI color tables: SGR parsing, color names, xterm's 256-color palette and reduction of
+//! RGB colors to 256 or 16 colors for terminals without TrueColor.
+
+/// xterm's default RGB values of the 16 standard colors (30–37, then 90–97).
+pub const STANDARD_COLORS: [[u8; 3]; 16] = [
+    [0, 0, 0],
+    [205, 0, 0],
+    [0, 205, 0],
+    [205, 205, 0],
+    [0, 0, 238],
+    [205, 0, 205],
+    [0, 205, 205],
+    [229, 229, 229],
+    [127, 127, 127],
+    [255, 0, 0],
+    [0, 255, 0],
+    [255, 255, 0],
+    [92, 92, 255],
+    [255, 0, 255],
+    [0, 255, 255],
+    [255, 255, 255],
+];
+
+/// Names of the 8 basic colors, in ANSI order.
+const COLOR_NAMES: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+/// RGB of a color written as a name ("cyan", "bright_blue", "light red"), as hex ("#1793d1",
+/// "#fff") or as SGR parameters ("1;34", "38;2;23;147;209").
+pub fn parse_color(s: &str) -> Option<[u8; 3]> {
+    let s = s.trim().to_lowercase();
+    if let Some(hex) = s.strip_prefix('#') {
+        return parse_hex(hex);
+    }
+    if s.chars().all(|c| c.is_ascii_digit() || c == ';') {
+        return parse_sgr_color(&s);
+    }
+    let s = s.replace([' ', '-'], "_");
+    let (name, bright) = match s
+        .strip_prefix("bright_")
+        .or_else(|| s.strip_prefix("light_"))
+    {
+        Some(base) => (base, true),
+        None => (s.as_str(), false),
+    };
+    let name = if name == "purple" { "magenta" } else { name };
+    let index = COLOR_NAMES.iter().position(|&n| n == name)?;
+    Some(STANDARD_COLORS[index + if bright { 8 } else { 0 }])
+}
+
+fn parse_hex(hex: &str) -> Option<[u8; 3]> {
+    let digit = |i: usize, len: usize| u8::from_str_radix(hex.get(i..i + len)?, 16).ok();
+    match hex.len() {
+        6 => Some([digit(0, 2)?, digit(2, 2)?, digit(4, 2)?]),
+        3 => Some([digit(0, 1)? * 17, digit(1, 1)? * 17, digit(2, 1)? * 17]),
+        _ => None,
+    }
+}
+
+/// Last foreground color set by the SGR parameter list `sgr` (e.g. "1;34").
+pub fn parse_sgr_color(sgr: &str) -> Option<[u8; 3]> {
+    let codes: Vec<u16> = sgr
+        .split(';')
+        .map(|c| c.trim().parse().unwrap_or(0))
+        .collect();
+    let byte = |i: usize| codes.get(i).and_then(|&c| u8::try_from(c).ok());
+    let mut color = None;
+    let mut i = 0;
+    while i < codes.len() {
+        match codes[i] {
+            38 if codes.get(i + 1) == Some(&2) => {
+                color = Some([byte(i + 2)?, byte(i + 3)?, byte(i + 4)?]);
+                i += 4;
+            }
+            38 if codes.get(i + 1) == Some(&5) => {
+                color = Some(xterm_256(byte(i + 2)?));
+                i += 2;
+            }
+            c @ 30..=37 => color = Some(STANDARD_COLORS[usize::from(c - 30)]),
+            c @ 90..=97 => color = Some(STANDARD_COLORS[usize::from(c - 90) + 8]),
+            _ => {}
+        }
+        i += 1;
+    }
+    color
+}
+
+/// RGB of an xterm 256-color index: 16 standard colors, a 6x6x6 cube, then 24 grays.
+pub fn xterm_256(n: u8) -> [u8; 3] {
+    const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    match n {
+        0..=15 => STANDARD_COLORS[usize::from(n)],
+        16..=231 => {
+            let n = usize::from(n - 16);
+            [LEVELS[n / 36], LEVELS[n / 6 % 6], LEVELS[n % 6]]
+        }
+        _ => {
+            let gray = 8 + (n - 232) * 10;
+            [gray, gray, gray]
+        }
+    }
+}
+
+/// Nearest 256-color index of `rgb` (cube or gray ramp; the 16 standard colors are skipped
+/// because terminals theme them).
+pub fn nearest_256(rgb: [u8; 3]) -> u8 {
+    let cube = |v: u8| -> u8 {
+        match v {
+            0..=47 => 0,
+            48..=114 => 1,
+            _ => (v - 35) / 40,
+        }
+    };
+    let [r, g, b] = rgb.map(cube);
+    let cube_index = 16 + 36 * r + 6 * g + b;
+    let avg = (u16::from(rgb[0]) + u16::from(rgb[1]) + u16::from(rgb[2])) / 3;
+    let gray_index = if avg > 238 {
+        255
+    } else {
+        232 + u8::try_from(avg.saturating_sub(3) / 10).unwrap_or(23)
+    };
+    if distance(xterm_256(gray_index), rgb) < distance(xterm_256(cube_index), rgb) {
+        gray_index
+    } else {
+        cube_index
+    }
+}
+
+/// Index (0–15) of the standard color nearest to `rgb`.
+pub fn nearest_16(rgb: [u8; 3]) -> u8 {
+    (0..16u8)
+        .min_by_key(|&i| distance(STANDARD_COLORS[usize::from(i)], rgb))
+        .unwrap_or(7)
+}
+
+/// Squared RGB distance.
+fn distance(a: [u8; 3], b: [u8; 3]) -> u32 {
+    a.iter()
+        .zip(b)
+        .map(|(&x, y)| (i32::from(x) - i32::from(y)).pow(2).unsigned_abs())
+        .sum()
+}
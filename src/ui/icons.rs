@@ -0,0 +1,85 @@
+//! Label icons: Nerd Font glyphs, plain Unicode symbols or emoji, all from the module registry.
+//! `[modules.<key>] icon` overrides the set for one module.
+
+use std::sync::Arc;
+
+use crate::cache::Cache;
+use crate::config::AppConfig;
+use crate::fetch::{self, Line};
+use crate::info;
+
+/// Which glyphs label icons are drawn from (`general.icon_set`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IconSet {
+    Nerd,
+    Unicode,
+    Emoji,
+    None,
+}
+
+impl IconSet {
+    /// Parses `general.icon_set`; unknown names fall back to Nerd Font icons.
+    pub fn parse(name: &str) -> Self {
+        match name.trim().to_lowercase().as_str() {
+            "unicode" => IconSet::Unicode,
+            "emoji" => IconSet::Emoji,
+            "none" | "off" => IconSet::None,
+            _ => IconSet::Nerd,
+        }
+    }
+
+    /// Icon set for this run: `use_nerd_fonts = false` turns icons off, and the Nerd set is
+    /// dropped when `detect_nerd_font` is on and the terminal font is known not to be a Nerd Font.
+    /// `lines` are this run's fetched lines (see `terminal_font`).
+    pub fn resolve(config: &AppConfig, lines: &[Line], cache: &Cache) -> Self {
+        if !config.general.use_nerd_fonts {
+            return IconSet::None;
+        }
+        let set = IconSet::parse(&config.general.icon_set);
+        if set == IconSet::Nerd
+            && config.general.detect_nerd_font
+            && terminal_font(config, lines, cache).is_some_and(|f| !is_nerd_font(&f))
+        {
+            return IconSet::None;
+        }
+        set
+    }
+
+    /// Icon for a layout key in this set. Empty string = no icon.
+    pub fn icon(self, key: &str) -> &'static str {
+        let Some(module) = info::find_module(key) else {
+            return "";
+        };
+        match self {
+            IconSet::Nerd => module.icon(),
+            IconSet::Unicode => module.unicode_icon(),
+            IconSet::Emoji => module.emoji_icon(),
+            IconSet::None => "",
+        }
+    }
+}
+
+/// Font reported by the terminal_font module: its line in `lines` when it is in the layout
+/// (None if it timed out), else the module run alone under its deadline.
+fn terminal_font(config: &AppConfig, lines: &[Line], cache: &Cache) -> Option<String> {
+    let is_font = |line: &&Line| line.module.key() == "terminal_font";
+    let probed;
+    let line = match lines.iter().find(is_font) {
+        Some(line) => line,
+        None => {
+            let mut probe = config.clone();
+            probe.layout = vec!["terminal_font".into()];
+            probed = fetch::collect_lines(&Arc::new(probe), None, cache);
+            probed.iter().find(is_font)?
+        }
+    };
+    line.item.as_ref()?.text("font").map(str::to_string)
+}
+
+/// Patched fonts are named "<Family> Nerd Font" or abbreviated "<Family> NF" / "NFM" / "NFP".
+fn is_nerd_font(name: &str) -> bool {
+    name.to_lowercase().contains("nerd")
+        || name
+            .split_whitespace()
+            .any(|w| matches!(w, "NF" | "NFM" | "NFP"))
+}
@@ -1,14 +1,29 @@
 //! Rendering of image/GIF logos via viuer (Sixel, Kitty, iTerm, or ANSI blocks).
 
+use image::{DynamicImage, GenericImageView};
+use std::io::Write;
 use std::path::Path;
 
+use super::render::{truncate, LogoLayout, Position};
+
 /// Default width in terminal cells when `width` is None (roughly 30–40 chars).
-const DEFAULT_IMAGE_WIDTH: u32 = 36;
+pub const DEFAULT_IMAGE_WIDTH: u32 = 36;
+
+/// Checks that `path` is a readable image without decoding it.
+pub fn check_image(path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    image::image_dimensions(path)
+        .map(|_| ())
+        .map_err(Into::into)
+}
 
-/// Prints an image (or GIF) from `path` to stdout using viu.
+/// Prints an image (or GIF) from `path` to stdout using viu, at the cursor position.
 /// Uses Sixel/Kitty/iTerm if supported, otherwise ANSI blocks.
-/// `width`: terminal cell width; if None, uses DEFAULT_IMAGE_WIDTH.
-pub fn print_image(path: &str, width: Option<u32>) -> Result<(), Box<dyn std::error::Error>> {
+/// `width`: terminal cell width; if None, uses DEFAULT_IMAGE_WIDTH. `padding`: columns to indent.
+pub fn print_image(
+    path: &str,
+    width: Option<u32>,
+    padding: usize,
+) -> Result<(), Box<dyn std::error::Error>> {
     let path = Path::new(path);
     if !path.exists() {
         return Err(std::io::Error::new(
@@ -21,6 +36,8 @@ pub fn print_image(path: &str, width: Option<u32>) -> Result<(), Box<dyn std::er
     let w = width.unwrap_or(DEFAULT_IMAGE_WIDTH);
     let config = viuer::Config {
         width: Some(w),
+        absolute_offset: false,
+        x: u16::try_from(padding).unwrap_or(u16::MAX),
         ..Default::default()
     };
 
@@ -28,3 +45,90 @@ pub fn print_image(path: &str, width: Option<u32>) -> Result<(), Box<dyn std::er
         .map(|_| ())
         .map_err::<Box<dyn std::error::Error>, _>(Into::into)
 }
+
+/// A decoded image logo and the terminal cells it will occupy.
+pub struct ImageLogo {
+    img: DynamicImage,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Loads the image at `path` and fits it, keeping its aspect ratio, into `max_width` cells
+/// (DEFAULT_IMAGE_WIDTH if None) by `max_height` rows. Small images are scaled up to fill the box.
+/// Animated images show their first frame.
+pub fn load_image(
+    path: &str,
+    max_width: Option<u32>,
+    max_height: usize,
+) -> Result<ImageLogo, Box<dyn std::error::Error>> {
+    let img = image::open(path)?;
+    let (width, height) = fit_cells(
+        img.dimensions(),
+        max_width.unwrap_or(DEFAULT_IMAGE_WIDTH),
+        u32::try_from(max_height).unwrap_or(u32::MAX).max(1),
+    );
+    Ok(ImageLogo { img, width, height })
+}
+
+/// Cell size of an image of `(w, h)` pixels fitted into `max_w` x `max_h` cells.
+/// A cell is twice as tall as it is wide.
+pub fn fit_cells((w, h): (u32, u32), max_w: u32, max_h: u32) -> (u32, u32) {
+    let (w, h) = (u64::from(w.max(1)), u64::from(h.max(1)));
+    let (max_w, max_h) = (u64::from(max_w), u64::from(max_h));
+    // Height in cells when using the full width; too tall means height is the limit
+    let full_width_h = h * max_w / w / 2;
+    let (cw, ch) = if full_width_h <= max_h {
+        (max_w, full_width_h)
+    } else {
+        (w * max_h * 2 / h, max_h)
+    };
+    let clamp = |v: u64| u32::try_from(v.max(1)).unwrap_or(u32::MAX);
+    (clamp(cw), clamp(ch))
+}
+
+/// Draws the image beside the info lines: the rows are reserved first, the image is printed at
+/// the top of that region (cursor restored afterwards), then each info line is written at the
+/// info column. Needs a terminal that understands cursor movement.
+pub fn print_beside(
+    logo: &ImageLogo,
+    info_lines: &[String],
+    layout: &LogoLayout,
+    width: Option<usize>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let logo_width = logo.width as usize;
+    let logo_height = logo.height as usize;
+    let info_width = layout.info_width(info_lines, logo_width, width);
+    let rows = logo_height.max(info_lines.len());
+    let (image_x, info_x) = match layout.position {
+        Position::Right => (layout.padding + info_width + layout.margin, layout.padding),
+        _ => (layout.padding, layout.padding + logo_width + layout.margin),
+    };
+
+    // Reserve the region so the terminal scrolls before anything is drawn, then go back up
+    let mut out = std::io::stdout().lock();
+    write!(out, "{}\x1b[{}A", "\n".repeat(rows), rows)?;
+    out.flush()?;
+    drop(out);
+
+    let config = viuer::Config {
+        width: Some(logo.width),
+        height: Some(logo.height),
+        absolute_offset: false,
+        x: u16::try_from(image_x).unwrap_or(u16::MAX),
+        y: i16::try_from(layout.offset(logo_height, rows)).unwrap_or(0),
+        restore_cursor: true,
+        ..Default::default()
+    };
+    let printed = viuer::print(&logo.img, &config);
+
+    let info_offset = layout.offset(info_lines.len(), rows);
+    let mut out = std::io::stdout().lock();
+    for row in 0..rows {
+        if let Some(line) = row.checked_sub(info_offset).and_then(|i| info_lines.get(i)) {
+            write!(out, "\r\x1b[{}G{}", info_x + 1, truncate(line, info_width))?;
+        }
+        writeln!(out)?;
+    }
+    out.flush()?;
+    printed.map(|_| ()).map_err(Into::into)
+}
@@ -0,0 +1,297 @@
+//! Image logos as text: a picture converted to ASCII ramps, half-blocks or braille dots, so it
+//! is laid out like a built-in ASCII logo and works without terminal graphics support.
+//! Converted logos are cached per image, size and style.
+
+use image::imageops::FilterType;
+use image::{DynamicImage, Rgba, RgbaImage};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::time::Duration;
+
+use super::color;
+use super::image_render::{fit_cells, DEFAULT_IMAGE_WIDTH};
+use crate::cache::{Cache, CachePolicy};
+
+/// Characters of the ASCII ramp, from least to most ink.
+const RAMP: &[u8] = b" .:-=+*#%@";
+
+/// Shades for half-block cells when colors are off, from least to most ink.
+const SHADES: [char; 4] = ['░', '▒', '▓', '█'];
+
+/// Pixels with less alpha than this are transparent.
+const ALPHA_CUTOFF: u8 = 128;
+
+/// Converted logos only change with the image (its mtime is a cache hint).
+const CACHE_TTL: Duration = Duration::from_secs(30 * 24 * 3600);
+
+/// Characters an image is drawn with (`general.image_renderer`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextRenderer {
+    /// One character per pixel from a density ramp (" .:-=+*#%@").
+    Ascii,
+    /// Two pixels per cell with "▀" (foreground on top, background below).
+    Blocks,
+    /// Eight dots per cell with braille characters (U+2800–U+28FF).
+    Braille,
+}
+
+impl TextRenderer {
+    /// Parses `general.image_renderer`; None = draw with terminal graphics ("graphics").
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "ascii" => Some(TextRenderer::Ascii),
+            "blocks" | "halfblocks" => Some(TextRenderer::Blocks),
+            "braille" => Some(TextRenderer::Braille),
+            _ => None,
+        }
+    }
+
+    /// Image pixels covered by one cell (columns, rows).
+    fn cell_pixels(self) -> (u32, u32) {
+        match self {
+            TextRenderer::Ascii => (1, 1),
+            TextRenderer::Blocks => (1, 2),
+            TextRenderer::Braille => (2, 4),
+        }
+    }
+}
+
+/// Colors available for converted images (`general.image_colors`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorDepth {
+    TrueColor,
+    Ansi256,
+    Ansi16,
+    None,
+}
+
+impl ColorDepth {
+    /// Parses `general.image_colors`; unknown names mean TrueColor.
+    pub fn parse(name: &str) -> Self {
+        match name.trim().to_lowercase().as_str() {
+            "256" => ColorDepth::Ansi256,
+            "16" => ColorDepth::Ansi16,
+            "none" | "off" => ColorDepth::None,
+            _ => ColorDepth::TrueColor,
+        }
+    }
+
+    /// SGR sequence setting the foreground (or background) to `rgb`.
+    fn escape(self, rgb: [u8; 3], background: bool) -> String {
+        let [r, g, b] = rgb;
+        match self {
+            ColorDepth::TrueColor => {
+                let code = if background { 48 } else { 38 };
+                format!("\x1b[{};2;{};{};{}m", code, r, g, b)
+            }
+            ColorDepth::Ansi256 => {
+                let code = if background { 48 } else { 38 };
+                format!("\x1b[{};5;{}m", code, color::nearest_256(rgb))
+            }
+            ColorDepth::Ansi16 => {
+                let n = color::nearest_16(rgb);
+                let base = match (background, n < 8) {
+                    (false, true) => 30,
+                    (false, false) => 82,
+                    (true, true) => 40,
+                    (true, false) => 92,
+                };
+                format!("\x1b[{}m", base + n)
+            }
+            ColorDepth::None => String::new(),
+        }
+    }
+}
+
+/// The image at `path` as text lines, fitted into `max_width` cells (DEFAULT_IMAGE_WIDTH if
+/// None) by `max_height` rows. The result is cached until the image file changes.
+pub fn text_logo(
+    path: &str,
+    max_width: Option<u32>,
+    max_height: usize,
+    renderer: TextRenderer,
+    depth: ColorDepth,
+    cache: &Cache,
+) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let (cols, rows) = fit_cells(
+        image::image_dimensions(path)?,
+        max_width.unwrap_or(DEFAULT_IMAGE_WIDTH),
+        u32::try_from(max_height).unwrap_or(u32::MAX).max(1),
+    );
+
+    let mut hasher = DefaultHasher::new();
+    (path, cols, rows, renderer, depth).hash(&mut hasher);
+    let key = format!("image_text-{:016x}", hasher.finish());
+    let policy = CachePolicy::new(CACHE_TTL).with_hint(path);
+
+    let mut error = None;
+    let lines = cache.get_or_try_insert(&key, &policy, || match image::open(path) {
+        Ok(img) => Some(convert(&img, cols, rows, renderer, depth)),
+        Err(e) => {
+            error = Some(e);
+            None
+        }
+    });
+    match (lines, error) {
+        (Some(lines), _) => Ok(lines),
+        (None, Some(e)) => Err(e.into()),
+        (None, None) => Err("image conversion failed".into()),
+    }
+}
+
+/// Converts `img` into `rows` lines of `cols` cells. Transparent areas become spaces.
+pub fn convert(
+    img: &DynamicImage,
+    cols: u32,
+    rows: u32,
+    renderer: TextRenderer,
+    depth: ColorDepth,
+) -> Vec<String> {
+    let (px_w, px_h) = renderer.cell_pixels();
+    let pixels = img
+        .resize_exact(cols * px_w, rows * px_h, FilterType::Triangle)
+        .to_rgba8();
+    // Without transparency, braille dots are the pixels brighter than average
+    let min_luma = if pixels.pixels().any(|p| p[3] < ALPHA_CUTOFF) {
+        0
+    } else {
+        let sum: u64 = pixels.pixels().map(|p| u64::from(luma(p))).sum();
+        u8::try_from(sum / u64::from((cols * px_w * rows * px_h).max(1))).unwrap_or(0)
+    };
+    (0..rows)
+        .map(|row| {
+            let mut line = Line::new(depth);
+            for col in 0..cols {
+                match renderer {
+                    TextRenderer::Ascii => ascii_cell(&mut line, pixels.get_pixel(col, row)),
+                    TextRenderer::Blocks => blocks_cell(&mut line, &pixels, col, row),
+                    TextRenderer::Braille => braille_cell(&mut line, &pixels, col, row, min_luma),
+                }
+            }
+            line.finish()
+        })
+        .collect()
+}
+
+fn ascii_cell(line: &mut Line, p: &Rgba<u8>) {
+    if p[3] < ALPHA_CUTOFF {
+        return line.blank();
+    }
+    let level = usize::from(luma(p)) * (RAMP.len() - 1) / 255;
+    // Colored output needs ink in every opaque pixel, or dark colors disappear
+    let level = if line.depth == ColorDepth::None {
+        level
+    } else {
+        level.max(RAMP.len() / 2)
+    };
+    line.push(char::from(RAMP[level]), Some(rgb(p)), None);
+}
+
+fn blocks_cell(line: &mut Line, pixels: &RgbaImage, col: u32, row: u32) {
+    let top = pixels.get_pixel(col, row * 2);
+    let bottom = pixels.get_pixel(col, row * 2 + 1);
+    let opaque = |p: &Rgba<u8>| p[3] >= ALPHA_CUTOFF;
+    if line.depth == ColorDepth::None {
+        let inked: Vec<&Rgba<u8>> = [top, bottom].into_iter().filter(|p| opaque(p)).collect();
+        if inked.is_empty() {
+            return line.blank();
+        }
+        let avg = inked.iter().map(|p| usize::from(luma(p))).sum::<usize>() / inked.len();
+        return line.push(SHADES[avg * SHADES.len() / 256], None, None);
+    }
+    match (opaque(top), opaque(bottom)) {
+        (false, false) => line.blank(),
+        (true, false) => line.push('▀', Some(rgb(top)), None),
+        (false, true) => line.push('▄', Some(rgb(bottom)), None),
+        (true, true) => line.push('▀', Some(rgb(top)), Some(rgb(bottom))),
+    }
+}
+
+/// Braille dot bits by (x, y) position inside the 2x4 cell.
+const BRAILLE_BITS: [[u32; 4]; 2] = [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+
+fn braille_cell(line: &mut Line, pixels: &RgbaImage, col: u32, row: u32, min_luma: u8) {
+    let mut bits = 0;
+    let mut sum = [0u32; 3];
+    let mut count = 0;
+    for (x, column) in BRAILLE_BITS.iter().enumerate() {
+        for (y, bit) in column.iter().enumerate() {
+            let p = pixels.get_pixel(col * 2 + x as u32, row * 4 + y as u32);
+            if p[3] >= ALPHA_CUTOFF && luma(p) >= min_luma {
+                bits |= bit;
+                for (s, c) in sum.iter_mut().zip(rgb(p)) {
+                    *s += u32::from(c);
+                }
+                count += 1;
+            }
+        }
+    }
+    if bits == 0 {
+        return line.blank();
+    }
+    let avg = sum.map(|s| u8::try_from(s / count).unwrap_or(u8::MAX));
+    let dots = char::from_u32(0x2800 + bits).unwrap_or(' ');
+    line.push(dots, Some(avg), None);
+}
+
+/// One output line; escapes are only written when the colors change.
+struct Line {
+    depth: ColorDepth,
+    text: String,
+    current: (Option<[u8; 3]>, Option<[u8; 3]>),
+}
+
+impl Line {
+    fn new(depth: ColorDepth) -> Self {
+        Self {
+            depth,
+            text: String::new(),
+            current: (None, None),
+        }
+    }
+
+    fn push(&mut self, c: char, fg: Option<[u8; 3]>, bg: Option<[u8; 3]>) {
+        if self.depth != ColorDepth::None && (fg, bg) != self.current {
+            if self.current.1.is_some() && bg.is_none() {
+                self.text.push_str("\x1b[0m");
+                self.current = (None, None);
+            }
+            if fg != self.current.0 {
+                if let Some(fg) = fg {
+                    self.text.push_str(&self.depth.escape(fg, false));
+                }
+            }
+            if let Some(bg) = bg.filter(|&bg| Some(bg) != self.current.1) {
+                self.text.push_str(&self.depth.escape(bg, true));
+            }
+            self.current = (fg.or(self.current.0), bg);
+        }
+        self.text.push(c);
+    }
+
+    /// A transparent cell: a space without background.
+    fn blank(&mut self) {
+        if self.current.1.is_some() {
+            self.text.push_str("\x1b[0m");
+            self.current = (None, None);
+        }
+        self.text.push(' ');
+    }
+
+    fn finish(mut self) -> String {
+        if self.current != (None, None) {
+            self.text.push_str("\x1b[0m");
+        }
+        self.text
+    }
+}
+
+fn rgb(p: &Rgba<u8>) -> [u8; 3] {
+    [p[0], p[1], p[2]]
+}
+
+/// Perceived brightness (Rec. 601).
+fn luma(p: &Rgba<u8>) -> u8 {
+    let [r, g, b] = rgb(p).map(u32::from);
+    u8::try_from((299 * r + 587 * g + 114 * b) / 1000).unwrap_or(u8::MAX)
+}
@@ -1,9 +1,158 @@
 //! Distro-specific ASCII art and primary colors.
 //! Data lives in ascii_data.rs; this module handles matching and colors.
+//! User logos (`[ascii] logo_path` or `~/.config/novafetch/logos/<name>.txt`) may use neofetch's
+//! `${c1}`..`${c6}` color markers.
 
-use colored::Color;
+use std::path::PathBuf;
 
-use super::ascii_data;
+use super::render::{display_width, LogoLayout};
+use super::{ascii_data, ascii_data_small};
+use crate::config::{expand_tilde, AppConfig, AsciiConfig};
+use crate::info::{self, OsRelease};
+
+/// Number of neofetch color markers (`${c1}`..`${c6}`).
+const MARKER_COUNT: usize = 6;
+
+/// Colors of a logo: `${c1}` is the first entry, `${c2}` the second, and so on.
+pub type Palette = &'static [[u8; 3]];
+
+/// Distro palettes (brand colors), named like the art constants.
+/// Art without markers is drawn entirely in the first color.
+mod palettes {
+    use super::Palette;
+
+    pub const ARCH: Palette = &[[23, 147, 209], [102, 204, 255]];
+    pub const DEBIAN: Palette = &[[215, 10, 83], [255, 255, 255]];
+    pub const UBUNTU: Palette = &[[233, 84, 32], [255, 255, 255]];
+    pub const FEDORA: Palette = &[[81, 162, 218], [255, 255, 255]];
+    pub const OPENSUSE: Palette = &[[115, 186, 37], [255, 255, 255]];
+    pub const GENTOO: Palette = &[[148, 112, 198], [255, 255, 255]];
+    pub const SLACKWARE: Palette = &[[71, 97, 155], [255, 255, 255]];
+    pub const RHEL: Palette = &[[238, 0, 0], [255, 255, 255]];
+    pub const MINT: Palette = &[[135, 207, 62], [255, 255, 255]];
+    pub const MANJARO: Palette = &[[53, 191, 92], [255, 255, 255]];
+    pub const ENDEAVOUROS: Palette = &[[127, 63, 191], [242, 103, 104], [127, 186, 221]];
+    pub const POP_OS: Palette = &[[72, 185, 199], [255, 255, 255]];
+    pub const MX_LINUX: Palette = &[[255, 255, 255], [84, 140, 84]];
+    pub const ZORIN: Palette = &[[21, 166, 240], [255, 255, 255]];
+    pub const ELEMENTARY: Palette = &[[100, 186, 255], [255, 255, 255]];
+    pub const KALI: Palette = &[[39, 126, 230], [255, 255, 255]];
+    pub const PARROT: Palette = &[[255, 255, 255], [5, 202, 225]];
+    pub const GARUDA: Palette = &[[0, 197, 205], [255, 255, 255]];
+    pub const NOBARA: Palette = &[[93, 186, 70], [255, 255, 255]];
+    pub const CACHYOS: Palette = &[[0, 184, 148], [255, 255, 255]];
+    pub const ALMALINUX: Palette = &[[255, 68, 68], [255, 204, 0], [0, 122, 255], [134, 218, 47]];
+    pub const ROCKY: Palette = &[[16, 185, 129], [255, 255, 255]];
+    pub const CENTOS: Palette = &[
+        [147, 43, 144],
+        [239, 166, 36],
+        [38, 36, 114],
+        [152, 188, 56],
+    ];
+    pub const ALPINE: Palette = &[[13, 89, 127], [255, 255, 255]];
+    pub const ORACLE_LINUX: Palette = &[[199, 70, 52], [255, 255, 255]];
+    pub const NIXOS: Palette = &[[82, 119, 195], [126, 186, 228]];
+    pub const VOID: Palette = &[[71, 128, 97], [255, 255, 255]];
+    pub const SOLUS: Palette = &[[82, 148, 226], [255, 255, 255]];
+    pub const PUPPY: Palette = &[[255, 221, 87], [255, 255, 255]];
+    pub const FREEBSD: Palette = &[[171, 43, 40], [255, 255, 255]];
+    pub const RASPBIAN: Palette = &[[197, 26, 74], [117, 168, 43]];
+    pub const WINDOWS10: Palette = &[[0, 120, 215], [0, 120, 215]];
+    pub const WINDOWS11: Palette = &[[0, 164, 239], [0, 164, 239]];
+    pub const MACOS: Palette = &[
+        [97, 187, 70],
+        [253, 184, 39],
+        [245, 130, 31],
+        [224, 58, 62],
+        [150, 61, 151],
+        [0, 157, 220],
+    ];
+    pub const FALLBACK: Palette = &[[255, 255, 255], [255, 204, 0]];
+}
+
+/// A built-in logo: names accepted by `--logo` / `distro_override` (the first is the one
+/// listed by `--list-logos`), full and small art, and palette.
+struct BuiltinLogo {
+    names: &'static [&'static str],
+    art: &'static str,
+    small: &'static str,
+    palette: Palette,
+}
+
+/// Full art, small art and palette of a logo share the constant name.
+macro_rules! logo {
+    ($name:ident, [$($alias:literal),+]) => {
+        BuiltinLogo {
+            names: &[$($alias),+],
+            art: ascii_data::$name,
+            small: ascii_data_small::$name,
+            palette: palettes::$name,
+        }
+    };
+}
+
+const LOGOS: &[BuiltinLogo] = &[
+    // Major families
+    logo!(ARCH, ["arch", "archlinux"]),
+    logo!(DEBIAN, ["debian"]),
+    logo!(UBUNTU, ["ubuntu"]),
+    logo!(FEDORA, ["fedora"]),
+    logo!(OPENSUSE, ["opensuse", "suse", "sles"]),
+    logo!(GENTOO, ["gentoo"]),
+    logo!(SLACKWARE, ["slackware"]),
+    logo!(RHEL, ["rhel", "redhat"]),
+    // Top derivatives
+    logo!(MINT, ["mint", "linuxmint"]),
+    logo!(MANJARO, ["manjaro"]),
+    logo!(ENDEAVOUROS, ["endeavouros", "endeavour"]),
+    logo!(POP_OS, ["pop_os", "popos", "pop!_os", "pop", "pop-os"]),
+    logo!(MX_LINUX, ["mx", "mxlinux"]),
+    logo!(ZORIN, ["zorin", "zorinos"]),
+    logo!(ELEMENTARY, ["elementary", "elementaryos"]),
+    logo!(KALI, ["kali", "kalilinux"]),
+    logo!(PARROT, ["parrot", "parrotos"]),
+    logo!(GARUDA, ["garuda", "garudalinux"]),
+    logo!(NOBARA, ["nobara", "nobaralinux"]),
+    logo!(CACHYOS, ["cachyos", "cachy"]),
+    // Server / Enterprise
+    logo!(ALMALINUX, ["almalinux", "alma"]),
+    logo!(ROCKY, ["rocky", "rockylinux"]),
+    logo!(CENTOS, ["centos"]),
+    logo!(ALPINE, ["alpine"]),
+    logo!(ORACLE_LINUX, ["oracle", "oraclelinux", "ol"]),
+    // Others
+    logo!(NIXOS, ["nixos", "nix"]),
+    logo!(VOID, ["void", "voidlinux"]),
+    logo!(SOLUS, ["solus"]),
+    logo!(PUPPY, ["puppy", "puppylinux"]),
+    logo!(FREEBSD, ["freebsd", "bsd"]),
+    logo!(RASPBIAN, ["raspbian", "raspberrypi", "raspi"]),
+    // Windows / macOS
+    logo!(WINDOWS10, ["windows10", "windows8", "windows7"]),
+    logo!(WINDOWS11, ["windows", "windows11"]),
+    logo!(MACOS, ["macos", "darwin", "apple", "mac"]),
+    logo!(FALLBACK, ["fallback"]),
+];
+
+/// Family fallback for unknown slugs: the first entry whose substring the slug contains
+/// (e.g. "kubuntu" -> ubuntu). Checked in order, so "cachy" wins over "arch".
+const FAMILIES: &[(&[&str], &str)] = &[
+    (&["cachy"], "cachyos"),
+    (&["arch"], "arch"),
+    (&["debian"], "debian"),
+    (&["ubuntu"], "ubuntu"),
+    (&["fedora", "rhel", "redhat"], "fedora"),
+    (&["suse", "sles"], "opensuse"),
+    (&["gentoo"], "gentoo"),
+    (&["mint"], "mint"),
+    (&["manjaro"], "manjaro"),
+    (&["centos", "rocky", "alma"], "rhel"),
+    (&["alpine"], "alpine"),
+    (&["kali"], "kali"),
+    (&["nix"], "nixos"),
+    (&["void"], "void"),
+    (&["bsd"], "freebsd"),
+];
 
 fn lines_from_raw(raw: &'static str) -> Vec<&'static str> {
     raw.trim_matches('\n').split('\n').collect()
@@ -14,143 +163,424 @@ pub fn normalize_slug(s: &str) -> String {
     s.to_lowercase().replace(' ', "")
 }
 
-/// Returns ASCII art lines and primary color for the given distro slug.
+/// Built-in logo for an exact name or alias.
+fn find_logo(name: &str) -> Option<&'static BuiltinLogo> {
+    LOGOS.iter().find(|l| l.names.contains(&name))
+}
+
+/// Built-in logo for a slug: exact name, else family fallback, else Tux.
+fn resolve_logo(slug: &str) -> &'static BuiltinLogo {
+    find_logo(slug)
+        .or_else(|| {
+            FAMILIES
+                .iter()
+                .find(|(needles, _)| needles.iter().any(|n| slug.contains(n)))
+                .and_then(|(_, name)| find_logo(name))
+        })
+        .or_else(|| find_logo("fallback"))
+        .expect("fallback logo is built in")
+}
+
+/// Returns ASCII art lines and palette for the given distro slug; `small` picks the small
+/// variant, as does a `_small` suffix (e.g. "arch_small").
 /// Uses family fallback (e.g. "kubuntu" -> Ubuntu) and finally Tux fallback.
-pub fn get_logo(slug: &str) -> (Vec<&'static str>, Color) {
+pub fn get_logo(slug: &str, small: bool) -> (Vec<&'static str>, Palette) {
     let slug = normalize_slug(slug);
+    let (slug, small) = match slug.strip_suffix("_small") {
+        Some(base) => (base, true),
+        None => (slug.as_str(), small),
+    };
+    let logo = resolve_logo(slug);
+    (
+        lines_from_raw(if small { logo.small } else { logo.art }),
+        logo.palette,
+    )
+}
+
+/// Logo picked for the running system (see `detect_logo`).
+pub struct DetectedLogo {
+    pub slug: String,
+    /// The distro's own color (os-release `ANSI_COLOR`) when the logo is borrowed from a
+    /// parent distro; it replaces the logo's first color.
+    pub color: Option<[u8; 3]>,
+}
+
+/// Logo for the running system: the os-release `ID` if there is a logo for it (built-in or
+/// user), else the distro's `LOGO` icon name, else the first `ID_LIKE` parent with a logo.
+/// Without any match, `get_logo` falls back by family name and finally to Tux.
+pub fn detect_logo() -> DetectedLogo {
+    detect_from(info::distro_slug(), OsRelease::get(), has_logo)
+}
+
+/// `detect_logo` for the distro `id` described by `release`, where `has_logo` tells which
+/// names have a logo.
+fn detect_from(
+    id: String,
+    release: Option<&OsRelease>,
+    has_logo: impl Fn(&str) -> bool,
+) -> DetectedLogo {
+    let Some(release) = release.filter(|_| !has_logo(&id)) else {
+        return DetectedLogo {
+            slug: id,
+            color: None,
+        };
+    };
+    let slug = release
+        .logo
+        .as_deref()
+        .map(logo_icon_name)
+        .into_iter()
+        .chain(release.id_like.iter().cloned())
+        .find(|name| has_logo(name))
+        .unwrap_or(id);
+    let borrowed = resolve_logo(&normalize_slug(&slug)).names[0] != "fallback";
+    DetectedLogo {
+        slug,
+        color: release.ansi_rgb().filter(|_| borrowed),
+    }
+}
+
+/// Distro name in an os-release `LOGO` icon name: "archlinux-logo" -> "archlinux",
+/// "distributor-logo-opensuse" -> "opensuse".
+fn logo_icon_name(icon: &str) -> String {
+    let icon = icon.trim().to_lowercase();
+    let icon = icon
+        .strip_prefix("distributor-logo-")
+        .or_else(|| icon.strip_prefix("start-here-"))
+        .unwrap_or(&icon);
+    icon.split("-logo").next().unwrap_or(icon).to_string()
+}
+
+/// True if `name` is a built-in logo name or alias, or a logo in the user logo directory.
+fn has_logo(name: &str) -> bool {
+    has_builtin_logo(name) || user_logo_file(&normalize_slug(name)).is_some()
+}
+
+/// True if `name` is a built-in logo name or alias.
+fn has_builtin_logo(name: &str) -> bool {
+    find_logo(&normalize_slug(name)).is_some()
+}
+
+/// `palette` with its first color replaced by the detected distro color, if any.
+pub fn with_color_hint(palette: Palette, color: Option<[u8; 3]>) -> Vec<[u8; 3]> {
+    let mut palette = palette.to_vec();
+    if let (Some(first), Some(color)) = (palette.first_mut(), color) {
+        *first = color;
+    }
+    palette
+}
+
+/// User logo directory: `~/.config/novafetch/logos` (XDG).
+pub fn user_logo_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|d| d.join("novafetch").join("logos"))
+}
+
+/// Art of a user logo: `[ascii] logo_path` if set, else `<name>.txt` (or `<name>`) from the
+/// user logo directory. None = use the built-in logo for `slug`.
+pub fn user_logo(ascii: &AsciiConfig, slug: &str) -> Option<String> {
+    let logo_path = ascii.logo_path.as_deref().map(str::trim);
+    if let Some(path) = logo_path.filter(|p| !p.is_empty()).map(expand_tilde) {
+        match std::fs::read_to_string(&path) {
+            Ok(art) => return Some(clean_art(&art)),
+            Err(e) => eprintln!("novafetch: cannot read logo '{}': {}", path.display(), e),
+        }
+    }
+    user_logo_file(slug)
+}
+
+/// Art of `<name>.txt` (or `<name>`) in the user logo directory.
+fn user_logo_file(name: &str) -> Option<String> {
+    let dir = user_logo_dir()?;
+    let name = name.trim().to_lowercase();
+    [format!("{}.txt", name), name]
+        .iter()
+        .find_map(|file| std::fs::read_to_string(dir.join(file)).ok())
+        .map(|art| clean_art(&art))
+}
 
-    // Direct match: slug -> (art constant, color)
-    let (raw, color) = match slug.as_str() {
-        // Major families
-        "arch" | "archlinux" => (ascii_data::ARCH, Color::Cyan),
-        "debian" => (ascii_data::DEBIAN, Color::Red),
-        "ubuntu" => (ascii_data::UBUNTU, Color::BrightRed),
-        "fedora" => (ascii_data::FEDORA, Color::Blue),
-        "opensuse" | "suse" | "sles" => (ascii_data::OPENSUSE, Color::Green),
-        "gentoo" => (ascii_data::GENTOO, Color::Magenta),
-        "slackware" => (ascii_data::SLACKWARE, Color::Blue),
-        "rhel" | "redhat" => (ascii_data::RHEL, Color::Red),
-        // Top derivatives
-        "linuxmint" | "mint" => (ascii_data::MINT, Color::Green),
-        "manjaro" => (ascii_data::MANJARO, Color::Green),
-        "endeavouros" | "endeavour" => (ascii_data::ENDEAVOUROS, Color::Magenta),
-        "popos" | "pop_os" | "pop!_os" => (ascii_data::POP_OS, Color::Cyan),
-        "mxlinux" | "mx" => (ascii_data::MX_LINUX, Color::Green),
-        "zorinos" | "zorin" => (ascii_data::ZORIN, Color::Blue),
-        "elementary" | "elementaryos" => (ascii_data::ELEMENTARY, Color::Blue),
-        "kali" | "kalilinux" => (ascii_data::KALI, Color::Blue),
-        "parrot" | "parrotos" => (ascii_data::PARROT, Color::BrightWhite),
-        "garuda" | "garudalinux" => (ascii_data::GARUDA, Color::Cyan),
-        "nobara" | "nobaralinux" => (ascii_data::NOBARA, Color::Green),
-        "cachyos" | "cachy" => (ascii_data::CACHYOS, Color::Cyan),
-        // Server / Enterprise
-        "almalinux" | "alma" => (ascii_data::ALMALINUX, Color::Red),
-        "rocky" | "rockylinux" => (ascii_data::ROCKY, Color::Blue),
-        "centos" => (ascii_data::CENTOS, Color::Blue),
-        "alpine" => (ascii_data::ALPINE, Color::Blue),
-        "oraclelinux" | "oracle" => (ascii_data::ORACLE_LINUX, Color::Red),
-        // Others
-        "nixos" | "nix" => (ascii_data::NIXOS, Color::Cyan),
-        "void" | "voidlinux" => (ascii_data::VOID, Color::Green),
-        "solus" => (ascii_data::SOLUS, Color::Blue),
-        "puppy" | "puppylinux" => (ascii_data::PUPPY, Color::BrightYellow),
-        "freebsd" | "bsd" => (ascii_data::FREEBSD, Color::Red),
-        "raspbian" | "raspberrypi" | "raspi" => (ascii_data::RASPBIAN, Color::Red),
-        // Windows / macOS
-        "windows10" | "windows8" | "windows7" => (ascii_data::WINDOWS10, Color::BrightBlue),
-        "windows11" | "windows" => (ascii_data::WINDOWS11, Color::BrightBlue),
-        "macos" | "darwin" | "apple" | "mac" => (ascii_data::MACOS, Color::BrightWhite),
-        "fallback" => (ascii_data::FALLBACK, Color::Cyan),
+/// Names of the logos in the user logo directory (file names without `.txt`), sorted.
+fn user_logo_names() -> Vec<String> {
+    let Some(entries) = user_logo_dir().and_then(|d| std::fs::read_dir(d).ok()) else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = entries
+        .flatten()
+        .filter(|e| e.path().is_file())
+        .filter_map(|e| {
+            let name = e.file_name().to_string_lossy().to_lowercase();
+            let name = name.strip_suffix(".txt").unwrap_or(&name).to_string();
+            (!name.is_empty()).then_some(name)
+        })
+        .collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Drops CRs and leading/trailing blank lines, like the built-in art.
+fn clean_art(art: &str) -> String {
+    art.replace('\r', "").trim_matches('\n').to_string()
+}
+
+/// Whether to show the small variant: `[ascii] size = "small"` or `"large"`, or with "auto"
+/// (default) when the full logo is taller than the info block or too wide to sit beside it.
+pub fn use_small(
+    size: &str,
+    slug: &str,
+    info_height: usize,
+    layout: &LogoLayout,
+    width: Option<usize>,
+) -> bool {
+    match size.trim().to_lowercase().as_str() {
+        "small" => true,
+        "large" => false,
         _ => {
-            // Family fallback: slug contains family name -> use that logo
-            let slug_lower = slug.as_str();
-            if slug_lower.contains("cachy") {
-                (ascii_data::CACHYOS, Color::Cyan)
-            } else if slug_lower.contains("arch") {
-                (ascii_data::ARCH, Color::Cyan)
-            } else if slug_lower.contains("debian") {
-                (ascii_data::DEBIAN, Color::Red)
-            } else if slug_lower.contains("ubuntu")
-                || slug_lower.contains("kubuntu")
-                || slug_lower.contains("xubuntu")
-                || slug_lower.contains("lubuntu")
-            {
-                (ascii_data::UBUNTU, Color::BrightRed)
-            } else if slug_lower.contains("fedora")
-                || slug_lower.contains("rhel")
-                || slug_lower.contains("redhat")
-            {
-                (ascii_data::FEDORA, Color::Blue)
-            } else if slug_lower.contains("suse") || slug_lower.contains("sles") {
-                (ascii_data::OPENSUSE, Color::Green)
-            } else if slug_lower.contains("gentoo") {
-                (ascii_data::GENTOO, Color::Magenta)
-            } else if slug_lower.contains("mint") {
-                (ascii_data::MINT, Color::Green)
-            } else if slug_lower.contains("manjaro") {
-                (ascii_data::MANJARO, Color::Green)
-            } else if slug_lower.contains("centos")
-                || slug_lower.contains("rocky")
-                || slug_lower.contains("alma")
-            {
-                (ascii_data::RHEL, Color::Blue)
-            } else if slug_lower.contains("alpine") {
-                (ascii_data::ALPINE, Color::Blue)
-            } else if slug_lower.contains("kali") {
-                (ascii_data::KALI, Color::Blue)
-            } else if slug_lower.contains("nix") {
-                (ascii_data::NIXOS, Color::Cyan)
-            } else if slug_lower.contains("void") {
-                (ascii_data::VOID, Color::Green)
-            } else if slug_lower.contains("freebsd") || slug_lower.contains("bsd") {
-                (ascii_data::FREEBSD, Color::Red)
-            } else {
-                (ascii_data::FALLBACK, Color::Cyan)
-            }
+            let (lines, _) = get_logo(slug, false);
+            let art_width = lines
+                .iter()
+                .map(|l| display_width(&strip_markers(l)))
+                .max()
+                .unwrap_or(0);
+            lines.len() > info_height || !layout.fits_beside(art_width, width)
         }
+    }
+}
+
+/// `art` without its color markers.
+pub fn strip_markers(art: &str) -> String {
+    paint_markers(art, &[], true).join("\n")
+}
+
+/// True if `art` uses any `${c1}`..`${c6}` marker.
+pub fn has_color_markers(art: &str) -> bool {
+    (1..=MARKER_COUNT).any(|n| art.contains(&format!("${{c{}}}", n)))
+}
+
+/// Colors for `${c1}`..`${c6}`: `[ascii] colors`, else the distro palette with
+/// `theme.mode = "distro"`, else the theme's primary, secondary and text colors.
+/// Markers past the end of the list wrap around.
+pub fn marker_palette(config: &AppConfig, distro: &[[u8; 3]]) -> Vec<[u8; 3]> {
+    if !config.ascii.colors.is_empty() {
+        config.ascii.colors.clone()
+    } else if is_distro_mode(config) {
+        distro.to_vec()
+    } else {
+        vec![
+            config.theme.primary_color,
+            config.theme.secondary_color,
+            config.theme.text_color,
+        ]
+    }
+}
+
+/// True with `theme.mode = "distro"`: logos in their own palette instead of the gradient.
+pub fn is_distro_mode(config: &AppConfig) -> bool {
+    config.theme.mode.eq_ignore_ascii_case("distro")
+}
+
+/// With `theme.colors_from_logo`, sets the theme's primary and secondary colors from the
+/// first two logo colors (`[ascii] colors`, else the distro palette) so labels match the logo.
+pub fn derive_theme_colors(config: &mut AppConfig, distro: &[[u8; 3]]) {
+    if !config.theme.colors_from_logo {
+        return;
+    }
+    let palette = if config.ascii.colors.is_empty() {
+        distro
+    } else {
+        &config.ascii.colors
     };
+    if let Some(&first) = palette.first() {
+        config.theme.secondary_color = palette.get(1).copied().unwrap_or(first);
+        config.theme.primary_color = first;
+    }
+}
+
+/// Like `paint_markers`, but art without markers is drawn entirely in the first color.
+pub fn paint_logo(art: &str, palette: &[[u8; 3]], no_color: bool) -> Vec<String> {
+    if has_color_markers(art) {
+        paint_markers(art, palette, no_color)
+    } else {
+        paint_markers(&format!("${{c1}}{}", art), palette, no_color)
+    }
+}
+
+/// Splits `art` into lines with `${cN}` markers turned into TrueColor escapes from `palette`
+/// (or removed when `no_color`). A color carries over to the following lines, as in neofetch.
+pub fn paint_markers(art: &str, palette: &[[u8; 3]], no_color: bool) -> Vec<String> {
+    let mut current: Option<[u8; 3]> = None;
+    art.split('\n')
+        .map(|line| {
+            let mut out = String::new();
+            if let (Some([r, g, b]), false) = (current, no_color) {
+                out.push_str(&format!("\x1b[38;2;{};{};{}m", r, g, b));
+            }
+            let mut rest = line;
+            while let Some(start) = rest.find("${c") {
+                out.push_str(&rest[..start]);
+                let tail = &rest[start..];
+                let marker = tail
+                    .get(3..5)
+                    .filter(|m| m.ends_with('}'))
+                    .and_then(|m| m[..1].parse::<usize>().ok())
+                    .filter(|n| (1..=MARKER_COUNT).contains(n));
+                match marker {
+                    Some(n) => {
+                        let rgb = palette.get((n - 1) % palette.len().max(1)).copied();
+                        current = rgb;
+                        if let (Some([r, g, b]), false) = (rgb, no_color) {
+                            out.push_str(&format!("\x1b[38;2;{};{};{}m", r, g, b));
+                        }
+                        rest = &tail[5..];
+                    }
+                    None => {
+                        out.push_str("${c");
+                        rest = &tail[3..];
+                    }
+                }
+            }
+            out.push_str(rest);
+            if !no_color && current.is_some() {
+                out.push_str("\x1b[0m");
+            }
+            out
+        })
+        .collect()
+}
+
+/// Canonical names of the built-in logos, in `--list-logos` order.
+pub fn supported_slugs() -> impl Iterator<Item = &'static str> {
+    LOGOS.iter().map(|l| l.names[0])
+}
+
+/// `--list-logos`: one line per built-in logo (name, then aliases), then the user logos.
+pub fn list_lines() -> Vec<String> {
+    let mut lines: Vec<String> = LOGOS
+        .iter()
+        .map(|l| match l.names {
+            [name] => name.to_string(),
+            [name, aliases @ ..] => format!("{:<14} {}", name, aliases.join(", ")),
+            [] => String::new(),
+        })
+        .collect();
+    let user = user_logo_names();
+    if !user.is_empty() {
+        lines.push(String::new());
+        lines.push(format!("User logos ({}):", user_logo_dir_display()));
+        lines.extend(user);
+    }
+    lines
+}
+
+fn user_logo_dir_display() -> String {
+    user_logo_dir().map_or_else(String::new, |d| d.display().to_string())
+}
+
+/// `--preview-logo`: the logo `name` in its colors. A user logo of that name wins, as in a
+/// normal run, and is drawn with `[ascii] colors`, the palette of the built-in logo it
+/// replaces or the theme colors (see `marker_palette`); a `_small` suffix picks the small
+/// built-in variant. Names that are neither a built-in logo, an alias nor a user logo are an
+/// error instead of falling back by family.
+pub fn preview_lines(
+    config: &AppConfig,
+    name: &str,
+    no_color: bool,
+) -> Result<Vec<String>, String> {
+    let name = normalize_slug(name);
+    let (lines, palette) = get_logo(&name, false);
+    if let Some(art) = user_logo_file(&name) {
+        // A user logo replacing a built-in one keeps its palette, as in a normal run
+        let palette = marker_palette(config, palette);
+        return Ok(paint_logo(&art, &palette, no_color));
+    }
+    let base = name.strip_suffix("_small").unwrap_or(&name);
+    if find_logo(base).is_none() {
+        return Err(format!("unknown logo '{}' (see --list-logos)", name));
+    }
+    Ok(paint_logo(&lines.join("\n"), palette, no_color))
+}
+
+/// Columns between two cells of the `--print-logos` grid.
+const GRID_GAP: usize = 3;
+
+/// `--print-logos`: all built-in logos (small variants) under their names, in as many
+/// columns as fit in `width` (80 when unknown).
+pub fn grid_lines(width: Option<usize>, no_color: bool) -> Vec<String> {
+    let cells: Vec<(&str, Vec<String>)> = supported_slugs()
+        .map(|name| {
+            let (lines, palette) = get_logo(name, true);
+            (name, paint_logo(&lines.join("\n"), palette, no_color))
+        })
+        .collect();
+    let cell_width = cells
+        .iter()
+        .flat_map(|(name, art)| art.iter().map(|s| display_width(s)).chain([name.len()]))
+        .max()
+        .unwrap_or(0);
+    let columns = ((width.unwrap_or(80) + GRID_GAP) / (cell_width + GRID_GAP)).max(1);
+
+    let mut out = Vec::new();
+    for row in cells.chunks(columns) {
+        let height = row.iter().map(|(_, art)| art.len()).max().unwrap_or(0);
+        for i in 0..=height {
+            let line: Vec<String> = row
+                .iter()
+                .map(|(name, art)| {
+                    let cell = if i == 0 {
+                        if no_color {
+                            name.to_string()
+                        } else {
+                            format!("\x1b[1m{}\x1b[0m", name)
+                        }
+                    } else {
+                        art.get(i - 1).cloned().unwrap_or_default()
+                    };
+                    let pad = cell_width.saturating_sub(display_width(&cell));
+                    format!("{}{}", cell, " ".repeat(pad))
+                })
+                .collect();
+            out.push(line.join(&" ".repeat(GRID_GAP)).trim_end().to_string());
+        }
+        out.push(String::new());
+    }
+    out.pop();
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Name of the built-in logo picked for an os-release file; user logos are left out.
+    fn detected(os_release: &str) -> (&'static str, Option<[u8; 3]>) {
+        let release = OsRelease::parse(os_release);
+        let id = release.id.clone().unwrap_or_default();
+        let logo = detect_from(id, Some(&release), has_builtin_logo);
+        let name = resolve_logo(&normalize_slug(&logo.slug)).names[0];
+        (name, logo.color)
+    }
+
+    #[test]
+    fn detects_pop_os() {
+        let pop = "NAME=\"Pop!_OS\"\nID=pop\nID_LIKE=\"ubuntu debian\"\n\
+                   LOGO=distributor-logo-pop-os\nANSI_COLOR=\"0;36\"\n";
+        assert_eq!(detected(pop), ("pop_os", None));
+        assert!(has_builtin_logo(&logo_icon_name("distributor-logo-pop-os")));
+    }
+
+    #[test]
+    fn detects_oracle_linux() {
+        let ol = "NAME=\"Oracle Linux Server\"\nID=\"ol\"\nID_LIKE=\"fedora\"\n\
+                  ANSI_COLOR=\"0;31\"\n";
+        assert_eq!(detected(ol), ("oracle", None));
+    }
 
-    (lines_from_raw(raw), color)
-}
-
-/// List of supported distro slug names for help/CLI.
-#[allow(dead_code)]
-pub fn supported_slugs() -> &'static [&'static str] {
-    &[
-        "arch",
-        "debian",
-        "ubuntu",
-        "fedora",
-        "opensuse",
-        "gentoo",
-        "slackware",
-        "rhel",
-        "mint",
-        "manjaro",
-        "endeavouros",
-        "pop_os",
-        "mx",
-        "zorin",
-        "elementary",
-        "kali",
-        "parrot",
-        "garuda",
-        "nobara",
-        "cachyos",
-        "almalinux",
-        "rocky",
-        "centos",
-        "alpine",
-        "oracle",
-        "nixos",
-        "void",
-        "solus",
-        "puppy",
-        "freebsd",
-        "raspbian",
-        "windows",
-        "windows11",
-        "macos",
-        "fallback",
-    ]
+    #[test]
+    fn detects_opensuse_tumbleweed_by_parent() {
+        let tumbleweed = "NAME=\"openSUSE Tumbleweed\"\nID=\"opensuse-tumbleweed\"\n\
+                          ID_LIKE=\"opensuse suse\"\nLOGO=\"distributor-logo-Tumbleweed\"\n\
+                          ANSI_COLOR=\"0;32\"\n";
+        let (name, color) = detected(tumbleweed);
+        assert_eq!(name, "opensuse");
+        assert_eq!(color, crate::ui::color::parse_sgr_color("0;32"));
+    }
 }
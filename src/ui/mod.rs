@@ -1,9 +1,16 @@
-mod ascii;
 mod ascii_data;
+mod ascii_data_small;
+pub mod color;
 pub mod gradient;
+pub mod icons;
 pub mod image_render;
+pub mod image_text;
 pub mod logos;
 mod render;
 pub mod theme;
+pub mod tree;
 
-pub use render::print_final_result;
+pub use render::{
+    display_width, print_final_result, print_lines, terminal_width, trim_blank_tail, LogoLayout,
+    Position,
+};
@@ -1,41 +1,238 @@
 //! Layout: side-by-side printing of logo and info lines.
 
-/// Visible character width of a string, ignoring ANSI escape sequences.
+use unicode_width::UnicodeWidthChar;
+
+use crate::config::AsciiConfig;
+
+/// Visible terminal width of a string: ANSI escape sequences (CSI such as colors, OSC such as
+/// hyperlinks) count as zero, wide characters (CJK, most emoji) as two columns.
 pub fn display_width(s: &str) -> usize {
     let mut width = 0;
-    let mut i = 0;
-    let bytes = s.as_bytes();
-    while i < bytes.len() {
-        if bytes[i] == b'\x1b' && i + 1 < bytes.len() && bytes[i + 1] == b'[' {
-            i += 2;
-            while i < bytes.len() && bytes[i] != b'm' {
-                i += 1;
+    let mut rest = s;
+    while let Some(c) = rest.chars().next() {
+        if c == '\x1b' {
+            rest = &rest[escape_len(rest)..];
+            continue;
+        }
+        width += UnicodeWidthChar::width(c).unwrap_or(0);
+        rest = &rest[c.len_utf8()..];
+    }
+    width
+}
+
+/// Byte length of the escape sequence at the start of `s` (which starts with ESC).
+fn escape_len(s: &str) -> usize {
+    let mut chars = s.char_indices().skip(1).peekable();
+    match chars.next() {
+        // CSI: parameters, then a final byte in '@'..='~'
+        Some((_, '[')) => {
+            for (i, c) in chars {
+                if ('@'..='~').contains(&c) {
+                    return i + c.len_utf8();
+                }
             }
-            if i < bytes.len() {
-                i += 1;
+        }
+        // OSC: terminated by BEL or ESC \
+        Some((_, ']')) => {
+            while let Some((i, c)) = chars.next() {
+                if c == '\x07' {
+                    return i + 1;
+                }
+                if c == '\x1b' {
+                    if let Some(&(j, '\\')) = chars.peek() {
+                        return j + 1;
+                    }
+                }
             }
+        }
+        Some((i, c)) => return i + c.len_utf8(),
+        None => return 1,
+    }
+    s.len()
+}
+
+/// Cuts `s` to at most `max` visible columns, ending with "…" when something was cut.
+/// Escape sequences are kept, and colors are reset after a cut.
+pub fn truncate(s: &str, max: usize) -> String {
+    if display_width(s) <= max {
+        return s.to_string();
+    }
+    let mut out = String::new();
+    let mut width = 0;
+    let mut rest = s;
+    let mut styled = false;
+    while let Some(c) = rest.chars().next() {
+        if c == '\x1b' {
+            let len = escape_len(rest);
+            out.push_str(&rest[..len]);
+            rest = &rest[len..];
+            styled = true;
             continue;
         }
-        if bytes[i] < 128 {
-            width += 1;
-            i += 1;
+        let w = UnicodeWidthChar::width(c).unwrap_or(0);
+        if width + w + 1 > max {
+            break;
+        }
+        out.push(c);
+        width += w;
+        rest = &rest[c.len_utf8()..];
+    }
+    if max > 0 {
+        out.push('…');
+    }
+    if styled {
+        out.push_str("\x1b[0m");
+    }
+    out
+}
+
+/// Columns available for output: `--width` if given, else the terminal's width.
+/// None when stdout is not a terminal (output is then never cut).
+pub fn terminal_width(cli_width: Option<usize>) -> Option<usize> {
+    cli_width.or_else(|| {
+        terminal_size::terminal_size().map(|(terminal_size::Width(w), _)| usize::from(w))
+    })
+}
+
+/// Prints lines indented by `padding` columns and cut to fit `width` (not cut when None).
+pub fn print_lines(lines: &[String], padding: usize, width: Option<usize>) {
+    let indent = " ".repeat(padding);
+    let width = width.map(|w| w.saturating_sub(padding));
+    for line in lines {
+        match width {
+            Some(w) => println!("{}{}", indent, truncate(line, w)),
+            None => println!("{}{}", indent, line),
+        }
+    }
+}
+
+/// `lines` without trailing blank lines, so stacked blocks are separated by exactly one.
+pub fn trim_blank_tail(lines: &[String]) -> &[String] {
+    let end = lines
+        .iter()
+        .rposition(|l| display_width(l) > 0)
+        .map_or(0, |i| i + 1);
+    &lines[..end]
+}
+
+/// Narrowest info column worth keeping beside the logo; below this the logo is stacked on top.
+const MIN_INFO_WIDTH: usize = 40;
+
+/// Where the logo goes relative to the info (`[ascii] position`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Position {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+/// Vertical alignment of the shorter column side by side (`[ascii] valign`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VAlign {
+    Top,
+    Center,
+    Bottom,
+}
+
+/// Logo placement, margin and padding from `[ascii]`.
+#[derive(Debug, Clone, Copy)]
+pub struct LogoLayout {
+    pub position: Position,
+    pub valign: VAlign,
+    pub margin: usize,
+    pub padding: usize,
+}
+
+impl LogoLayout {
+    /// Unknown position/valign names fall back to "left"/"top".
+    pub fn from_config(ascii: &AsciiConfig) -> Self {
+        let position = match ascii.position.trim().to_lowercase().as_str() {
+            "right" => Position::Right,
+            "top" => Position::Top,
+            "bottom" => Position::Bottom,
+            _ => Position::Left,
+        };
+        let valign = match ascii.valign.trim().to_lowercase().as_str() {
+            "center" | "middle" => VAlign::Center,
+            "bottom" => VAlign::Bottom,
+            _ => VAlign::Top,
+        };
+        Self {
+            position,
+            valign,
+            margin: ascii.margin,
+            padding: ascii.padding,
+        }
+    }
+
+    /// True if an info column of at least `MIN_INFO_WIDTH` fits beside a logo `logo_width`
+    /// columns wide (always when the width is unknown).
+    pub fn fits_beside(&self, logo_width: usize, width: Option<usize>) -> bool {
+        width
+            .map(|w| w.saturating_sub(self.padding))
+            .is_none_or(|w| logo_width + self.margin + MIN_INFO_WIDTH <= w)
+    }
+
+    /// True if the logo goes above or below the info: by position, or because the info column
+    /// would be too narrow beside it.
+    pub fn stacked(&self, logo_width: usize, width: Option<usize>) -> bool {
+        match self.position {
+            Position::Top | Position::Bottom => true,
+            Position::Left | Position::Right => !self.fits_beside(logo_width, width),
+        }
+    }
+
+    /// Columns the info column may use with a logo `logo_width` wide: what is left beside it,
+    /// or the whole width when stacked. None when the width is unknown.
+    pub fn info_columns(&self, logo_width: usize, width: Option<usize>) -> Option<usize> {
+        let beside = if self.stacked(logo_width, width) {
+            0
         } else {
-            i += 1;
-            while i < bytes.len() && (bytes[i] & 0xC0) == 0x80 {
-                i += 1;
-            }
-            width += 1;
+            logo_width + self.margin
+        };
+        width.map(|w| w.saturating_sub(self.padding + beside))
+    }
+
+    /// Width of the info column beside the logo: its widest line, cut to what is left.
+    pub fn info_width(
+        &self,
+        info_lines: &[String],
+        logo_width: usize,
+        width: Option<usize>,
+    ) -> usize {
+        let widest = info_lines
+            .iter()
+            .map(|s| display_width(s))
+            .max()
+            .unwrap_or(0);
+        match width {
+            Some(w) => widest.min(w.saturating_sub(self.padding + logo_width + self.margin)),
+            None => widest,
+        }
+    }
+
+    /// Rows to skip before a column of `len` lines so it lines up with one of `rows` lines.
+    pub fn offset(&self, len: usize, rows: usize) -> usize {
+        let spare = rows.saturating_sub(len);
+        match self.valign {
+            VAlign::Top => 0,
+            VAlign::Center => spare / 2,
+            VAlign::Bottom => spare,
         }
     }
-    width
 }
 
-/// Prints logo lines and info lines side-by-side. Logo is left-padded to a fixed width, then margin, then info.
-/// When logo is shorter than info, remaining info lines are indented to maintain alignment.
+/// Prints logo lines and info lines side-by-side (or stacked for "top"/"bottom").
+/// The shorter column is aligned against the taller one per `layout.valign`, and padded so
+/// the other column stays aligned.
+/// With a known `width`, info lines are cut to the columns left beside the logo; when fewer than
+/// `MIN_INFO_WIDTH` are left, the logo is printed above the info instead.
 pub fn print_final_result(
     logo_lines: &[String],
     info_lines: &[String],
-    margin: usize,
+    layout: &LogoLayout,
+    width: Option<usize>,
 ) {
     // Calculate the maximum display width of the logo (ignoring ANSI codes)
     let logo_width = logo_lines
@@ -43,30 +240,40 @@ pub fn print_final_result(
         .map(|s| display_width(s))
         .max()
         .unwrap_or(0);
-    
+    if layout.stacked(logo_width, width) {
+        let (first, second) = if layout.position == Position::Bottom {
+            (info_lines, logo_lines)
+        } else {
+            (logo_lines, info_lines)
+        };
+        print_lines(trim_blank_tail(first), layout.padding, width);
+        println!();
+        print_lines(second, layout.padding, width);
+        return;
+    }
+    let indent = " ".repeat(layout.padding);
+    let info_width = layout.info_width(info_lines, logo_width, width);
+
     let num_rows = logo_lines.len().max(info_lines.len());
-    let gap = " ".repeat(margin);
-    let empty_logo_padding = " ".repeat(logo_width);
+    let logo_offset = layout.offset(logo_lines.len(), num_rows);
+    let info_offset = layout.offset(info_lines.len(), num_rows);
+    let gap = " ".repeat(layout.margin);
 
     for i in 0..num_rows {
-        // Get logo line or use empty padding if logo is finished
-        if let Some(logo_line) = logo_lines.get(i) {
-            let logo_visible = display_width(logo_line);
-            let pad_spaces = logo_width.saturating_sub(logo_visible);
-            print!("{}{}", logo_line, " ".repeat(pad_spaces));
-        } else {
-            // Logo finished: print spaces equal to logo_width to maintain alignment
-            print!("{}", empty_logo_padding);
-        }
-        
-        // Print gap between logo and info
-        print!("{}", gap);
-
-        // Print info line if available
-        if let Some(info) = info_lines.get(i) {
-            print!("{}", info);
-        }
-        
-        println!();
+        let logo = cell(logo_lines, i.checked_sub(logo_offset), logo_width);
+        let info = cell(info_lines, i.checked_sub(info_offset), info_width);
+        let line = match layout.position {
+            Position::Right => format!("{}{}{}{}", indent, info, gap, logo),
+            _ => format!("{}{}{}{}", indent, logo, gap, info),
+        };
+        println!("{}", line.trim_end());
     }
 }
+
+/// Row `i` of a column, cut and padded to exactly `width` columns (blank outside the column).
+fn cell(lines: &[String], i: Option<usize>, width: usize) -> String {
+    let line = i.and_then(|i| lines.get(i)).map_or("", String::as_str);
+    let line = truncate(line, width);
+    let pad = width.saturating_sub(display_width(&line));
+    format!("{}{}", line, " ".repeat(pad))
+}
@@ -1,33 +1,9 @@
 //! Theming engine: gradient/solid labels, Nerd Font icons, value alignment.
 
-use crate::config::{AppConfig, GeneralConfig, ThemeConfig};
+use crate::config::{AppConfig, ModuleConfig, ThemeConfig};
 use crate::ui::gradient;
-use std::fmt::Write;
-
-/// Nerd Font icon (Unicode private use) per layout key. Empty string = no icon.
-fn nerd_icon_for_key(key: &str) -> &'static str {
-    match key {
-        "user_host" => "\u{f007}",  // fa-user
-        "os" => "\u{f17c}",          // fa-linux
-        "kernel" => "\u{f109}",      // fa-cog
-        "uptime" => "\u{f017}",      // fa-clock-o
-        "shell" => "\u{f489}",       // fa-terminal (alt)
-        "de" => "\u{f1e6}",          // fa-desktop
-        "cpu" => "\u{f0e4}",         // fa-microchip
-        "gpu" => "\u{f108}",         // fa-microchip / display
-        "memory" => "\u{f2db}",      // fa-memory
-        "disk" => "\u{f0a0}",        // fa-hdd-o
-        "terminal" => "\u{f120}",    // fa-terminal
-        "terminal_font" => "\u{f031}", // fa-font
-        "packages" => "\u{f187}",    // fa-cube
-        "resolution" => "\u{f108}",  // fa-desktop
-        "swap" => "\u{f2db}",        // fa-exchange
-        "os_age" => "\u{f073}",      // fa-calendar
-        "media" => "\u{f001}",       // fa-music
-        "local_ip" => "\u{f0ac}",    // fa-globe
-        _ => "",
-    }
-}
+use crate::ui::icons::IconSet;
+use std::collections::BTreeMap;
 
 /// ANSI TrueColor for foreground: \x1b[38;2;R;G;Bm
 fn ansi_rgb(rgb: [u8; 3]) -> String {
@@ -35,37 +11,45 @@ fn ansi_rgb(rgb: [u8; 3]) -> String {
 }
 
 pub struct ThemeManager<'a> {
-    pub general: &'a GeneralConfig,
     pub theme: &'a ThemeConfig,
+    pub modules: &'a BTreeMap<String, ModuleConfig>,
+    pub icons: IconSet,
     pub no_color: bool,
 }
 
 impl<'a> ThemeManager<'a> {
-    pub fn new(config: &'a AppConfig, no_color: bool) -> Self {
+    pub fn new(config: &'a AppConfig, icons: IconSet, no_color: bool) -> Self {
         Self {
-            general: &config.general,
             theme: &config.theme,
+            modules: &config.modules,
+            icons,
             no_color,
         }
     }
 
-    /// Format label: optional Nerd icon + gradient or solid primary color.
-    pub fn format_label(&self, key: &str, text: &str) -> String {
-        if self.no_color {
-            return text.to_string();
+    /// Label icon for a layout key: `[modules.<key>] icon` if set, else the icon set's glyph.
+    /// Empty string = no icon.
+    pub fn icon(&self, key: &str) -> &str {
+        if self.icons == IconSet::None {
+            return "";
         }
-        let mut out = String::new();
-        if self.general.use_nerd_fonts {
-            let icon = nerd_icon_for_key(key);
-            if !icon.is_empty() {
-                write!(out, "{} ", icon).ok();
-            }
+        match self.modules.get(key).and_then(|m| m.icon.as_deref()) {
+            Some(icon) => icon,
+            None => self.icons.icon(key),
         }
-        let labeled = if out.is_empty() {
+    }
+
+    /// Format label: optional icon + gradient or solid primary color.
+    pub fn format_label(&self, key: &str, text: &str) -> String {
+        let icon = self.icon(key);
+        let labeled = if icon.is_empty() {
             text.to_string()
         } else {
-            format!("{}{}", out, text)
+            format!("{} {}", icon, text)
         };
+        if self.no_color {
+            return labeled;
+        }
         if self.theme.mode.to_lowercase() == "gradient" {
             let start = (
                 self.theme.primary_color[0],
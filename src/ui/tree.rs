@@ -0,0 +1,286 @@
+//! Info line arrangement: tree sections with headers and glyphs, or flat `label: value` lines,
+//! with `general.separator` between label and value and optional value alignment.
+//! Sections come from `[[display.sections]]`, else from each module's category.
+
+use crate::config::{AppConfig, DisplayConfig, TreeGlyphs};
+use crate::info::{self, Category};
+use crate::ui::render::{display_width, truncate};
+use crate::ui::theme::ThemeManager;
+
+/// Labels wider than this are ellipsized (long disk mount points), so one label does not push
+/// every value to the right.
+const MAX_LABEL_WIDTH: usize = 24;
+
+/// Columns kept for the value in a narrow info column: the label side is cut first.
+const MIN_VALUE_WIDTH: usize = 16;
+
+/// One display line: layout key, label (as shown), formatted value.
+struct Entry<'a> {
+    key: &'a str,
+    label: String,
+    value: &'a str,
+}
+
+/// Rendered lines of a section before alignment: optional header, then (head, value) rows.
+struct Block {
+    header: Option<String>,
+    rows: Vec<(String, String)>,
+}
+
+struct Section<'a> {
+    title: String,
+    header_style: &'a str,
+    color: [u8; 3],
+    glyphs: &'a TreeGlyphs,
+    entries: Vec<Entry<'a>>,
+}
+
+/// Builds the info column from display lines (key, label, value) in layout order.
+/// Modules without a category (palette) are printed last, outside any section.
+/// With a known column `width`, label sides are cut so values keep `MIN_VALUE_WIDTH` columns.
+pub fn build_info_lines(
+    lines: &[(String, String, String)],
+    config: &AppConfig,
+    theme: &ThemeManager,
+    no_color: bool,
+    width: Option<usize>,
+) -> Vec<String> {
+    let display = &config.display;
+    let mut grouped = Vec::new();
+    let mut trailing = Vec::new();
+    for (key, label, value) in lines {
+        let Some(module) = info::find_module(key) else {
+            continue;
+        };
+        let listed = display
+            .sections
+            .iter()
+            .any(|s| s.modules.iter().any(|m| m == key));
+        if module.category().is_none() && !listed {
+            trailing.push(value);
+            continue;
+        }
+        let label = if label.is_empty() {
+            module.label()
+        } else {
+            label
+        };
+        let label = truncate(label, MAX_LABEL_WIDTH);
+        grouped.push(Entry { key, label, value });
+    }
+
+    let flat = display.mode.eq_ignore_ascii_case("flat");
+    let blocks = if flat {
+        vec![Block {
+            header: None,
+            rows: grouped
+                .iter()
+                .map(|e| {
+                    (
+                        theme.format_label(e.key, &e.label),
+                        theme.format_value(e.value),
+                    )
+                })
+                .collect(),
+        }]
+    } else {
+        sections(grouped, display)
+            .iter()
+            .filter(|s| !s.entries.is_empty())
+            .map(|s| tree_block(s, display, theme, no_color))
+            .collect()
+    };
+
+    let mut result = Vec::new();
+    let general = &config.general;
+    let max_head =
+        width.map(|w| w.saturating_sub(display_width(&general.separator) + MIN_VALUE_WIDTH));
+    let global_width = blocks
+        .iter()
+        .flat_map(|b| &b.rows)
+        .map(|(head, _)| display_width(head))
+        .max()
+        .unwrap_or(0);
+    for block in &blocks {
+        result.extend(block.header.clone());
+        let width = if general.align_scope.eq_ignore_ascii_case("global") {
+            global_width
+        } else {
+            block
+                .rows
+                .iter()
+                .map(|(head, _)| display_width(head))
+                .max()
+                .unwrap_or(0)
+        };
+        let width = max_head.map_or(width, |max| width.min(max));
+        for (head, value) in &block.rows {
+            let head = match max_head {
+                Some(max) => truncate(head, max),
+                None => head.clone(),
+            };
+            let pad = if general.align_values {
+                width.saturating_sub(display_width(&head))
+            } else {
+                0
+            };
+            result.push(format!(
+                "{}{}{}{}",
+                head,
+                " ".repeat(pad),
+                general.separator,
+                value
+            ));
+        }
+        if !flat && display.section_gap {
+            result.push(String::new());
+        }
+    }
+
+    if flat && !trailing.is_empty() {
+        result.push(String::new());
+    }
+    for value in trailing {
+        result.push(theme.format_value(value));
+    }
+    result
+}
+
+/// Splits entries into the configured sections, or Hardware/Software/Status when none are set.
+/// With configured sections, an entry goes to the first section listing its key; unlisted
+/// entries end up in an untitled section at the end.
+fn sections<'a>(entries: Vec<Entry<'a>>, display: &'a DisplayConfig) -> Vec<Section<'a>> {
+    let section = |title: &str| Section {
+        title: title.to_string(),
+        header_style: &display.header_style,
+        color: display.color,
+        glyphs: &display.glyphs,
+        entries: Vec::new(),
+    };
+
+    if display.sections.is_empty() {
+        let categories = [Category::Hardware, Category::Software, Category::Status];
+        let mut out: Vec<Section> = categories.iter().map(|c| section(c.name())).collect();
+        for entry in entries {
+            let category = info::find_module(entry.key).and_then(|m| m.category());
+            if let Some(idx) = categories.iter().position(|&c| Some(c) == category) {
+                out[idx].entries.push(entry);
+            }
+        }
+        return out;
+    }
+
+    let mut out: Vec<Section> = display
+        .sections
+        .iter()
+        .map(|s| Section {
+            title: s.title.clone(),
+            header_style: s.header_style.as_deref().unwrap_or(&display.header_style),
+            color: s.color.unwrap_or(display.color),
+            glyphs: s.glyphs.as_ref().unwrap_or(&display.glyphs),
+            entries: Vec::new(),
+        })
+        .collect();
+    let mut unlisted = section("");
+    unlisted.header_style = "none";
+    for entry in entries {
+        let idx = display
+            .sections
+            .iter()
+            .position(|s| s.modules.iter().any(|m| m == entry.key));
+        match idx {
+            Some(idx) => out[idx].entries.push(entry),
+            None => unlisted.entries.push(entry),
+        }
+    }
+    out.push(unlisted);
+    out
+}
+
+/// Section header line for `style`; None for "none" (or an empty title).
+fn header(title: &str, style: &str, width: usize, fill: &str) -> Option<String> {
+    let fill = if fill.is_empty() { " " } else { fill };
+    let title_width = title.chars().count();
+    match style.to_lowercase().as_str() {
+        "none" => None,
+        _ if title.is_empty() => None,
+        "plain" => Some(title.to_string()),
+        "left" => {
+            let right = width.saturating_sub(title_width + 4);
+            Some(format!(
+                "{} {} {}",
+                fill.repeat(2),
+                title,
+                fill.repeat(right)
+            ))
+        }
+        // "centered" (default)
+        _ => {
+            let total = width.saturating_sub(title_width);
+            let left = total / 2;
+            Some(format!(
+                "{}{}{}",
+                fill.repeat(left),
+                title,
+                fill.repeat(total - left)
+            ))
+        }
+    }
+}
+
+/// Header and rows of one tree section. Each row's head is the colored glyph plus the label;
+/// glyph widths count towards value alignment.
+fn tree_block(
+    section: &Section,
+    display: &DisplayConfig,
+    theme: &ThemeManager,
+    no_color: bool,
+) -> Block {
+    let color = if no_color {
+        String::new()
+    } else {
+        format!(
+            "\x1b[38;2;{};{};{}m",
+            section.color[0], section.color[1], section.color[2]
+        )
+    };
+    let header = header(
+        &section.title,
+        section.header_style,
+        display.header_width,
+        &section.glyphs.fill,
+    )
+    .map(|h| paint(&color, &h));
+
+    let count = section.entries.len();
+    let rows = section
+        .entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let glyph = if i == 0 {
+                &section.glyphs.first
+            } else if i == count - 1 {
+                &section.glyphs.last
+            } else {
+                &section.glyphs.middle
+            };
+            let head = format!(
+                "{}{}",
+                paint(&color, glyph),
+                theme.format_label(entry.key, &entry.label)
+            );
+            (head, theme.format_value(entry.value))
+        })
+        .collect();
+    Block { header, rows }
+}
+
+/// Wraps `text` in `color` + reset; plain text when color is empty (`--no-color`).
+fn paint(color: &str, text: &str) -> String {
+    if color.is_empty() || text.trim().is_empty() {
+        text.to_string()
+    } else {
+        format!("{}{}\x1b[0m", color, text)
+    }
+}